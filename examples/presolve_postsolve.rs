@@ -15,11 +15,10 @@ fn build_problem() -> Problem {
 }
 
 fn main() {
-    let mut presolver = Presolver::new();
-    let status = presolver.presolve(build_problem());
+    let presolver = Presolver::new().presolve(build_problem());
 
     let reduced = presolver.reduced_problem();
-    println!("presolve status      : {status:?}");
+    println!("presolve status      : {:?}", presolver.status());
     println!(
         "original cols / rows : {} / {}",
         presolver.num_cols(),
@@ -19,8 +19,7 @@
 //! problem.add_row("r1", &[(x, 1.0)], 2.5, f64::INFINITY);
 //!
 //! // Presolve it.
-//! let mut presolver = Presolver::new();
-//! let status = presolver.presolve(problem);
+//! let presolver = Presolver::new().presolve(problem);
 //!
 //! // Inspect the reduced problem.
 //! let reduced = presolver.reduced_problem();
@@ -33,6 +32,8 @@
 //! assert_eq!(original, vec![3.0]);
 //! ```
 
+/// Numerical diagnostics of problem data.
+pub mod numerics;
 /// Solver parameter handling.
 pub mod param;
 /// Types describing the result of presolving and postsolving.
@@ -41,6 +42,17 @@ pub mod presolve;
 pub mod presolver;
 /// Contains wrappers for the Papilo_Problem struct and its methods.
 pub mod problem;
+/// Bound propagation on problem data.
+pub mod propagation;
+/// Side-by-side summary of a presolve run.
+pub mod report;
+/// Problem statistics and constraint classification.
+pub mod statistics;
+#[cfg(any(feature = "sprs", feature = "nalgebra-sparse", feature = "faer"))]
+mod sparse;
+#[cfg(feature = "serde")]
+mod serde_float;
+mod model;
 
 /// Re-export the FFI bindings to allow direct access to the underlying C functions.
 pub use papilo_sys as ffi;
@@ -0,0 +1,231 @@
+//! A borrowed view of problem data, shared by the analyses that work on
+//! [`Problem`](crate::problem::Problem), [`OriginalProblem`] and
+//! [`ReducedProblem`] alike.
+
+use crate::presolve::{FeasibilityReport, OriginalProblem, ReducedProblem, Tolerances};
+use crate::problem::ProblemData;
+
+/// Column, row and CSR matrix data of a problem.
+#[derive(Clone, Copy)]
+pub(crate) struct ModelRef<'a> {
+    pub(crate) col_lower: &'a [f64],
+    pub(crate) col_upper: &'a [f64],
+    pub(crate) col_integral: &'a [bool],
+    pub(crate) objective: &'a [f64],
+    pub(crate) row_lhs: &'a [f64],
+    pub(crate) row_rhs: &'a [f64],
+    pub(crate) row_start: &'a [usize],
+    pub(crate) col_indices: &'a [usize],
+    pub(crate) values: &'a [f64],
+}
+
+impl<'a> ModelRef<'a> {
+    pub(crate) fn num_cols(&self) -> usize {
+        self.col_lower.len()
+    }
+
+    pub(crate) fn num_rows(&self) -> usize {
+        self.row_lhs.len()
+    }
+
+    /// Column indices and values of the nonzeros of `row`.
+    pub(crate) fn row(&self, row: usize) -> (&'a [usize], &'a [f64]) {
+        let range = self.row_start[row]..self.row_start[row + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+
+    /// Activity `a'x` of each row for `solution`, which must have one entry
+    /// per column.
+    pub(crate) fn row_activities(&self, solution: &[f64]) -> Vec<f64> {
+        (0..self.num_rows())
+            .map(|row| {
+                let (cols, values) = self.row(row);
+                cols.iter().zip(values).map(|(&col, a)| a * solution[col]).sum()
+            })
+            .collect()
+    }
+
+    /// Objective value `c'x` of `solution`, which must have one entry per
+    /// column.
+    pub(crate) fn objective_value(&self, solution: &[f64]) -> f64 {
+        self.objective.iter().zip(solution).map(|(c, x)| c * x).sum()
+    }
+
+    /// Evaluates bounds, integrality and row activities of `solution`, which
+    /// must have one entry per column.
+    pub(crate) fn check_feasibility(
+        &self,
+        solution: &[f64],
+        tolerances: &Tolerances,
+    ) -> FeasibilityReport {
+        let mut report = FeasibilityReport {
+            objective: self.objective_value(solution),
+            ..FeasibilityReport::default()
+        };
+
+        for (col, &x) in solution.iter().enumerate() {
+            let violation = self.bound_violation(col, x);
+            if violation > 0.0 {
+                report.max_bound_violation = report.max_bound_violation.max(violation);
+                if violation > tolerances.feasibility {
+                    report.violated_cols.push(col);
+                }
+            }
+
+            if let Some(fractionality) = self.fractionality(col, x) {
+                report.max_integrality_violation =
+                    report.max_integrality_violation.max(fractionality);
+                if fractionality > tolerances.integrality {
+                    report.fractional_cols.push(col);
+                }
+            }
+        }
+
+        for (row, activity) in self.row_activities(solution).into_iter().enumerate() {
+            let violation = self.row_violation(row, activity);
+            if violation > 0.0 {
+                report.max_row_violation = report.max_row_violation.max(violation);
+                if violation > tolerances.feasibility {
+                    report.violated_rows.push(row);
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Largest violation of a bound, integrality requirement or row side by
+    /// `solution`, which must have one entry per column; `0` if there is none.
+    pub(crate) fn max_violation(&self, solution: &[f64]) -> f64 {
+        let cols = solution.iter().enumerate().map(|(col, &x)| {
+            let fractionality = self.fractionality(col, x).unwrap_or(0.0);
+            self.bound_violation(col, x).max(fractionality)
+        });
+        let rows = self
+            .row_activities(solution)
+            .into_iter()
+            .enumerate()
+            .map(|(row, activity)| self.row_violation(row, activity));
+        cols.chain(rows).fold(0.0, f64::max)
+    }
+
+    /// Violation of the bounds of `col` by `x`; infinite if `x` is not finite.
+    fn bound_violation(&self, col: usize, x: f64) -> f64 {
+        if !x.is_finite() {
+            return f64::INFINITY;
+        }
+        (self.col_lower[col] - x).max(x - self.col_upper[col])
+    }
+
+    /// Distance of `x` to the nearest integer if `col` is integer, infinite if
+    /// `x` is not finite.
+    fn fractionality(&self, col: usize, x: f64) -> Option<f64> {
+        if !self.col_integral[col] {
+            return None;
+        }
+        if !x.is_finite() {
+            return Some(f64::INFINITY);
+        }
+        Some((x - x.round()).abs())
+    }
+
+    /// Violation of the sides of `row` by `activity`; infinite if `activity`
+    /// is not finite.
+    fn row_violation(&self, row: usize, activity: f64) -> f64 {
+        if !activity.is_finite() {
+            return f64::INFINITY;
+        }
+        (self.row_lhs[row] - activity).max(activity - self.row_rhs[row])
+    }
+}
+
+impl ReducedProblem {
+    pub(crate) fn model(&self) -> ModelRef<'_> {
+        ModelRef {
+            col_lower: &self.col_lower,
+            col_upper: &self.col_upper,
+            col_integral: &self.col_integral,
+            objective: &self.objective,
+            row_lhs: &self.row_lhs,
+            row_rhs: &self.row_rhs,
+            row_start: &self.row_start,
+            col_indices: &self.col_indices,
+            values: &self.values,
+        }
+    }
+}
+
+impl OriginalProblem {
+    pub(crate) fn model(&self) -> ModelRef<'_> {
+        ModelRef {
+            col_lower: &self.col_lower,
+            col_upper: &self.col_upper,
+            col_integral: &self.col_integral,
+            objective: &self.objective,
+            row_lhs: &self.row_lhs,
+            row_rhs: &self.row_rhs,
+            row_start: &self.row_start,
+            col_indices: &self.col_indices,
+            values: &self.values,
+        }
+    }
+}
+
+impl ProblemData {
+    /// A view of the data with the matrix given in CSR form, see
+    /// [`csr`](ProblemData::csr).
+    pub(crate) fn model<'a>(
+        &'a self,
+        row_start: &'a [usize],
+        col_indices: &'a [usize],
+        values: &'a [f64],
+    ) -> ModelRef<'a> {
+        ModelRef {
+            col_lower: &self.col_lower,
+            col_upper: &self.col_upper,
+            col_integral: &self.col_integral,
+            objective: &self.objective,
+            row_lhs: &self.row_lhs,
+            row_rhs: &self.row_rhs,
+            row_start,
+            col_indices,
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::presolve::OriginalProblem;
+
+    /// Nonzeros `(column, value)`, left- and right-hand side of a row.
+    pub(crate) type Row<'a> = (&'a [(usize, f64)], f64, f64);
+
+    /// Builds a problem with columns `(lower, upper, integral)`, a zero
+    /// objective and the given rows. Columns are named `x<index>` and rows
+    /// `r<index>`.
+    pub(crate) fn original_problem(cols: &[(f64, f64, bool)], rows: &[Row<'_>]) -> OriginalProblem {
+        let mut problem = OriginalProblem {
+            num_cols: cols.len(),
+            num_rows: rows.len(),
+            col_lower: cols.iter().map(|c| c.0).collect(),
+            col_upper: cols.iter().map(|c| c.1).collect(),
+            col_integral: cols.iter().map(|c| c.2).collect(),
+            objective: vec![0.0; cols.len()],
+            col_names: (0..cols.len()).map(|col| format!("x{col}")).collect(),
+            row_start: vec![0],
+            ..Default::default()
+        };
+        for (index, (entries, lhs, rhs)) in rows.iter().enumerate() {
+            for &(col, value) in *entries {
+                problem.col_indices.push(col);
+                problem.values.push(value);
+            }
+            problem.row_start.push(problem.values.len());
+            problem.row_lhs.push(*lhs);
+            problem.row_rhs.push(*rhs);
+            problem.row_names.push(format!("r{index}"));
+        }
+        problem
+    }
+}
@@ -0,0 +1,314 @@
+use crate::model::ModelRef;
+use crate::presolve::{OriginalProblem, ReducedProblem};
+use crate::problem::Problem;
+use crate::statistics::ValueRange;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Thresholds used by the numerics checks.
+///
+/// The defaults follow PaPILO's own defaults for the corresponding presolver
+/// parameters. Use
+/// [`Presolver::numerics_thresholds`](crate::presolver::Presolver::numerics_thresholds)
+/// to get thresholds that match the parameters set on a presolver.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericsThresholds {
+    /// Nonzero coefficients with an absolute value at or below this are
+    /// reported as tiny. Default `1e-9` (`numerics.epsilon`). PaPILO treats
+    /// values this small as zero.
+    pub tiny: f64,
+    /// Coefficients, bounds and sides with an absolute value at or above this
+    /// are reported as huge. Default `1e8` (`numerics.hugeval`). PaPILO does
+    /// not use values this large in activity-based reductions.
+    pub huge: f64,
+    /// Rows and columns where the largest to smallest absolute coefficient
+    /// ratio exceeds this are reported. Default `1e6`.
+    pub max_range: f64,
+    /// Two rows are reported as near-parallel when their scaled coefficients
+    /// differ by at most this much. Default `1e-6` (`numerics.feastol`).
+    pub parallel: f64,
+}
+
+impl Default for NumericsThresholds {
+    fn default() -> Self {
+        NumericsThresholds {
+            tiny: 1e-9,
+            huge: 1e8,
+            max_range: 1e6,
+            parallel: 1e-6,
+        }
+    }
+}
+
+/// Numerical issues found in a problem, as returned by
+/// [`ReducedProblem::numerics_report`], [`OriginalProblem::numerics_report`]
+/// and [`Problem::numerics_report`].
+///
+/// Indices refer to the rows and columns of the problem the report was computed
+/// for. All lists are sorted.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NumericsReport {
+    /// Range of the absolute values of the nonzero matrix coefficients.
+    pub coefficient_range: Option<ValueRange>,
+    /// Largest coefficient range ratio of any row (`1` if there are none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub max_row_range: f64,
+    /// Largest coefficient range ratio of any column (`1` if there are none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub max_col_range: f64,
+    /// Nonzeros `(row, column)` with a tiny absolute value.
+    pub tiny_coefficients: Vec<(usize, usize)>,
+    /// Nonzeros `(row, column)` with a huge absolute value.
+    pub huge_coefficients: Vec<(usize, usize)>,
+    /// Rows whose coefficient range exceeds the threshold.
+    pub wide_rows: Vec<usize>,
+    /// Columns whose coefficient range exceeds the threshold.
+    pub wide_cols: Vec<usize>,
+    /// Columns with a finite but huge bound, which should probably be infinite.
+    pub huge_bounds: Vec<usize>,
+    /// Rows with a finite but huge side, which should probably be infinite.
+    pub huge_sides: Vec<usize>,
+    /// Pairs `(first, row)` where `row` is a multiple of the earlier row
+    /// `first`, up to the tolerance. Rows with fewer than two nonzeros are not
+    /// considered.
+    pub parallel_rows: Vec<(usize, usize)>,
+}
+
+impl NumericsReport {
+    /// Whether any issue was found.
+    pub fn has_issues(&self) -> bool {
+        !(self.tiny_coefficients.is_empty()
+            && self.huge_coefficients.is_empty()
+            && self.wide_rows.is_empty()
+            && self.wide_cols.is_empty()
+            && self.huge_bounds.is_empty()
+            && self.huge_sides.is_empty()
+            && self.parallel_rows.is_empty())
+    }
+
+    /// Whether the coefficients are spread wider than in `other`, overall or in
+    /// the worst row or column. Compare the report of the reduced problem with
+    /// that of the original to find out whether presolving made things worse.
+    pub fn is_worse_than(&self, other: &NumericsReport) -> bool {
+        let ratio = |report: &NumericsReport| report.coefficient_range.map_or(1.0, |r| r.ratio());
+        ratio(self) > ratio(other)
+            || self.max_row_range > other.max_row_range
+            || self.max_col_range > other.max_col_range
+    }
+}
+
+impl ReducedProblem {
+    /// Checks the reduced problem for numerically problematic values.
+    pub fn numerics_report(&self, thresholds: &NumericsThresholds) -> NumericsReport {
+        NumericsReport::compute(self.model(), thresholds)
+    }
+}
+
+impl OriginalProblem {
+    /// Checks the original problem for numerically problematic values.
+    pub fn numerics_report(&self, thresholds: &NumericsThresholds) -> NumericsReport {
+        NumericsReport::compute(self.model(), thresholds)
+    }
+}
+
+impl Problem {
+    /// Checks the problem built so far for numerically problematic values.
+    pub fn numerics_report(&self, thresholds: &NumericsThresholds) -> NumericsReport {
+        let data = self.data();
+        let (row_start, col_indices, values) = data.csr();
+        NumericsReport::compute(data.model(&row_start, &col_indices, &values), thresholds)
+    }
+}
+
+impl NumericsReport {
+    pub(crate) fn compute(model: ModelRef<'_>, thresholds: &NumericsThresholds) -> Self {
+        let is_huge = |value: f64| value.is_finite() && value.abs() >= thresholds.huge;
+        let mut report = NumericsReport {
+            coefficient_range: ValueRange::of(model.values.iter().copied()),
+            max_row_range: 1.0,
+            max_col_range: 1.0,
+            ..Default::default()
+        };
+
+        let mut col_ranges: Vec<Option<ValueRange>> = vec![None; model.num_cols()];
+        for row in 0..model.num_rows() {
+            let (cols, values) = model.row(row);
+            for (&col, &value) in cols.iter().zip(values) {
+                if value != 0.0 && value.abs() <= thresholds.tiny {
+                    report.tiny_coefficients.push((row, col));
+                }
+                if is_huge(value) {
+                    report.huge_coefficients.push((row, col));
+                }
+                col_ranges[col] = ValueRange::of(
+                    col_ranges[col]
+                        .iter()
+                        .flat_map(|r| [r.min, r.max])
+                        .chain([value]),
+                );
+            }
+
+            if let Some(range) = ValueRange::of(values.iter().copied()) {
+                report.max_row_range = report.max_row_range.max(range.ratio());
+                if range.ratio() > thresholds.max_range {
+                    report.wide_rows.push(row);
+                }
+            }
+            if is_huge(model.row_lhs[row]) || is_huge(model.row_rhs[row]) {
+                report.huge_sides.push(row);
+            }
+        }
+
+        for (col, range) in col_ranges.iter().enumerate() {
+            if let Some(range) = range {
+                report.max_col_range = report.max_col_range.max(range.ratio());
+                if range.ratio() > thresholds.max_range {
+                    report.wide_cols.push(col);
+                }
+            }
+            if is_huge(model.col_lower[col]) || is_huge(model.col_upper[col]) {
+                report.huge_bounds.push(col);
+            }
+        }
+
+        report.parallel_rows = parallel_rows(&model, thresholds.parallel);
+        report
+    }
+}
+
+/// Finds rows that are multiples of an earlier row. Rows are grouped by their
+/// sparsity pattern and scaled so that their largest coefficient is `1` with
+/// the sign of their first; rows in the same group are then compared entry by
+/// entry.
+fn parallel_rows(model: &ModelRef<'_>, tolerance: f64) -> Vec<(usize, usize)> {
+    let mut groups: HashMap<&[usize], Vec<(usize, Vec<f64>)>> = HashMap::new();
+    let mut pairs = Vec::new();
+    for row in 0..model.num_rows() {
+        let (cols, values) = model.row(row);
+        if cols.len() < 2 {
+            continue;
+        }
+        let max = values.iter().fold(0.0_f64, |max, v| max.max(v.abs()));
+        if max == 0.0 || !max.is_finite() {
+            continue;
+        }
+        let scale = max.copysign(values[0]);
+        let scaled: Vec<f64> = values.iter().map(|v| v / scale).collect();
+
+        let group = groups.entry(cols).or_default();
+        let first = group.iter().find(|(_, other)| {
+            scaled
+                .iter()
+                .zip(other)
+                .all(|(a, b)| (a - b).abs() <= tolerance)
+        });
+        match first {
+            Some(&(first, _)) => pairs.push((first, row)),
+            None => group.push((row, scaled)),
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+impl fmt::Display for NumericsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.coefficient_range {
+            Some(range) => writeln!(f, "coefficients: {range} (ratio {:.1e})", range.ratio())?,
+            None => writeln!(f, "coefficients: -")?,
+        }
+        writeln!(
+            f,
+            "max range:    row {:.1e}, column {:.1e}",
+            self.max_row_range, self.max_col_range
+        )?;
+        write!(
+            f,
+            "issues:       {} tiny, {} huge coefficients, {} wide rows, {} wide columns, \
+             {} huge bounds, {} huge sides, {} parallel rows",
+            self.tiny_coefficients.len(),
+            self.huge_coefficients.len(),
+            self.wide_rows.len(),
+            self.wide_cols.len(),
+            self.huge_bounds.len(),
+            self.huge_sides.len(),
+            self.parallel_rows.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::{Row, original_problem};
+
+    const INF: f64 = f64::INFINITY;
+
+    /// Three continuous columns in `[0, 10]`.
+    fn problem(rows: &[Row<'_>]) -> OriginalProblem {
+        original_problem(&[(0.0, 10.0, false); 3], rows)
+    }
+
+    #[test]
+    fn well_scaled_problem_has_no_issues() {
+        let report = problem(&[
+            (&[(0, 1.0), (1, 2.0)], -INF, 4.0),
+            (&[(1, 1.0), (2, -1.0)], 0.0, INF),
+        ])
+        .numerics_report(&NumericsThresholds::default());
+
+        assert!(!report.has_issues(), "{report:?}");
+        assert_eq!(
+            report.coefficient_range,
+            Some(ValueRange { min: 1.0, max: 2.0 })
+        );
+        assert_eq!(report.max_row_range, 2.0);
+        assert_eq!(report.max_col_range, 2.0);
+    }
+
+    #[test]
+    fn flags_tiny_and_huge_values() {
+        let mut problem = problem(&[
+            (&[(0, 1e-12), (1, 1.0)], -INF, 4.0),
+            (&[(1, 1.0), (2, 1e9)], -1e10, INF),
+            (&[(2, 1.0)], -INF, 1.0),
+        ]);
+        problem.col_upper[2] = 1e20;
+        let report = problem.numerics_report(&NumericsThresholds::default());
+
+        assert_eq!(report.tiny_coefficients, vec![(0, 0)]);
+        assert_eq!(report.huge_coefficients, vec![(1, 2)]);
+        assert_eq!(report.wide_rows, vec![0, 1]);
+        assert_eq!(report.wide_cols, vec![2]);
+        assert_eq!(report.huge_bounds, vec![2]);
+        assert_eq!(report.huge_sides, vec![1]);
+        assert_eq!(report.max_row_range, 1e12);
+    }
+
+    #[test]
+    fn finds_near_parallel_rows() {
+        let report = problem(&[
+            (&[(0, 1.0), (1, 2.0)], -INF, 4.0),
+            (&[(0, -3.0), (1, -6.0 + 1e-8)], 0.0, INF),
+            (&[(0, 1.0), (1, 2.1)], -INF, 4.0),
+            (&[(0, 2.0), (2, 4.0)], -INF, 4.0),
+            (&[(0, 0.5), (1, 1.0)], 1.0, 1.0),
+        ])
+        .numerics_report(&NumericsThresholds::default());
+
+        assert_eq!(report.parallel_rows, vec![(0, 1), (0, 4)]);
+    }
+
+    #[test]
+    fn compares_reports() {
+        let thresholds = NumericsThresholds::default();
+        let good = problem(&[(&[(0, 1.0), (1, 2.0)], -INF, 4.0)]).numerics_report(&thresholds);
+        let bad = problem(&[(&[(0, 1.0), (1, 200.0)], -INF, 4.0)]).numerics_report(&thresholds);
+
+        assert!(bad.is_worse_than(&good));
+        assert!(!good.is_worse_than(&bad));
+        assert!(!good.is_worse_than(&good));
+    }
+}
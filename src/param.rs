@@ -40,7 +40,9 @@ impl Parameter for f64 {
         let c_key = std::ffi::CString::new(key).expect("parameter key contains a nul byte");
         check(unsafe {
             ffi::papilo_presolver_set_param_real(presolver.raw(), c_key.as_ptr(), value)
-        })
+        })?;
+        presolver.record_real_param(key, value);
+        Ok(())
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::ffi;
 
 /// The outcome of a [`Presolver::presolve`](crate::presolver::Presolver::presolve) call.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PresolveStatus {
     /// The problem was not changed by presolving.
@@ -44,6 +45,7 @@ impl From<ffi::PAPILO_PRESOLVE_STATUS> for PresolveStatus {
 ///
 /// Infinite bounds and row sides are represented as `f64::INFINITY` /
 /// `f64::NEG_INFINITY`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReducedProblem {
     /// Number of columns (variables) in the reduced problem.
@@ -51,18 +53,23 @@ pub struct ReducedProblem {
     /// Number of rows (constraints) in the reduced problem.
     pub num_rows: usize,
     /// Lower bound of each column (`f64::NEG_INFINITY` if unbounded below).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
     pub col_lower: Vec<f64>,
     /// Upper bound of each column (`f64::INFINITY` if unbounded above).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
     pub col_upper: Vec<f64>,
     /// Whether each column is integer-constrained.
     pub col_integral: Vec<bool>,
     /// Objective coefficient of each column.
     pub objective: Vec<f64>,
     /// Constant objective offset.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
     pub objective_offset: f64,
     /// Left-hand side of each row (`f64::NEG_INFINITY` if none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
     pub row_lhs: Vec<f64>,
     /// Right-hand side of each row (`f64::INFINITY` if none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
     pub row_rhs: Vec<f64>,
     /// CSR row pointers; `row_start[r]..row_start[r + 1]` indexes into
     /// [`col_indices`](Self::col_indices) / [`values`](Self::values) for row `r`.
@@ -90,9 +97,252 @@ impl ReducedProblem {
         let end = self.row_start[row + 1];
         (start..end).map(move |k| (self.col_indices[k], self.values[k]))
     }
+
+    /// Returns a copy of the constraint matrix in column-major (CSC) form,
+    /// e.g. to iterate over columns with [`CscMatrix::col`].
+    ///
+    /// The transpose is not cached: all fields of a `ReducedProblem` are
+    /// public and may be changed, which would leave a cached copy stale.
+    /// Build it once per reduced problem and keep it alongside.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CSR fields are inconsistent, see [`CscMatrix::from_csr`].
+    pub fn to_csc(&self) -> CscMatrix {
+        CscMatrix::from_csr(
+            self.num_cols,
+            &self.row_start,
+            &self.col_indices,
+            &self.values,
+        )
+    }
+
+    /// Objective value of a solution of the reduced problem, including
+    /// [`objective_offset`](Self::objective_offset).
+    ///
+    /// `solution` must have one entry per reduced column. For a reduced
+    /// solution `z`, this equals the original objective value of
+    /// `postsolve(z)` (see
+    /// [`Presolver::original_objective`](crate::presolver::Presolver::original_objective)).
+    pub fn objective_value(&self, solution: &[f64]) -> Result<f64, WrongLength> {
+        self.check_len(solution)?;
+        let value: f64 = self
+            .objective
+            .iter()
+            .zip(solution)
+            .map(|(c, x)| c * x)
+            .sum();
+        Ok(value + self.objective_offset)
+    }
+
+    /// Activity `a'x` of each reduced row for a solution of the reduced
+    /// problem, which must have one entry per reduced column.
+    pub fn row_activities(&self, solution: &[f64]) -> Result<Vec<f64>, WrongLength> {
+        self.check_len(solution)?;
+        Ok(self.model().row_activities(solution))
+    }
+
+    /// Slacks `(activity - lhs, rhs - activity)` of each reduced row for a
+    /// solution of the reduced problem. A negative slack means the side is
+    /// violated; a missing side has infinite slack.
+    pub fn slacks(&self, solution: &[f64]) -> Result<Vec<(f64, f64)>, WrongLength> {
+        let activities = self.row_activities(solution)?;
+        Ok(activities
+            .into_iter()
+            .enumerate()
+            .map(|(row, activity)| (activity - self.row_lhs[row], self.row_rhs[row] - activity))
+            .collect())
+    }
+
+    /// Checks a solution of the reduced problem against its column bounds,
+    /// integrality requirements and rows, e.g. to validate solver output
+    /// before [`postsolve`](crate::presolver::Presolver::postsolve).
+    ///
+    /// The report's objective includes
+    /// [`objective_offset`](Self::objective_offset) and its indices refer to
+    /// reduced columns and rows.
+    pub fn check_feasibility(
+        &self,
+        solution: &[f64],
+        tolerances: &Tolerances,
+    ) -> Result<FeasibilityReport, WrongLength> {
+        self.check_len(solution)?;
+        let mut report = self.model().check_feasibility(solution, tolerances);
+        report.objective += self.objective_offset;
+        Ok(report)
+    }
+
+    /// Largest violation of a column bound, row side or integrality
+    /// requirement by a solution of the reduced problem; `0` if there is none.
+    pub fn max_violation(&self, solution: &[f64]) -> Result<f64, WrongLength> {
+        self.check_len(solution)?;
+        Ok(self.model().max_violation(solution))
+    }
+
+    /// Whether a solution of the reduced problem satisfies all column bounds,
+    /// integrality requirements and rows within `tolerances`.
+    pub fn is_feasible(
+        &self,
+        solution: &[f64],
+        tolerances: &Tolerances,
+    ) -> Result<bool, WrongLength> {
+        self.check_feasibility(solution, tolerances)
+            .map(|report| report.is_feasible())
+    }
+
+    fn check_len(&self, solution: &[f64]) -> Result<(), WrongLength> {
+        if solution.len() != self.num_cols {
+            return Err(WrongLength {
+                expected: self.num_cols,
+                got: solution.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A sparse matrix in compressed sparse column (CSC) form, the transpose layout
+/// of the CSR arrays in [`ReducedProblem`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CscMatrix {
+    /// CSC column pointers; `col_start[c]..col_start[c + 1]` indexes into
+    /// [`row_indices`](Self::row_indices) / [`values`](Self::values) for column
+    /// `c`. Has length `num_cols + 1`.
+    pub col_start: Vec<usize>,
+    /// Row index of each nonzero, in CSC order. Within a column, row indices
+    /// are increasing.
+    pub row_indices: Vec<usize>,
+    /// Value of each nonzero, in CSC order.
+    pub values: Vec<f64>,
+}
+
+impl CscMatrix {
+    /// Transposes a CSR matrix with `num_cols` columns into CSC form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a column index is not below `num_cols`, if `values` and
+    /// `col_indices` differ in length, or if `row_start` does not start at `0`
+    /// and end at the number of nonzeros.
+    pub fn from_csr(
+        num_cols: usize,
+        row_start: &[usize],
+        col_indices: &[usize],
+        values: &[f64],
+    ) -> Self {
+        let nnz = col_indices.len();
+        assert_eq!(
+            values.len(),
+            nnz,
+            "values and col_indices must have the same length"
+        );
+        assert!(
+            row_start.first() == Some(&0) && row_start.last() == Some(&nnz),
+            "row_start must start at 0 and end at the number of nonzeros ({nnz})"
+        );
+        let mut col_start = vec![0; num_cols + 1];
+        for &col in col_indices {
+            col_start[col + 1] += 1;
+        }
+        for col in 0..num_cols {
+            col_start[col + 1] += col_start[col];
+        }
+
+        let mut next = col_start.clone();
+        let mut row_indices = vec![0; nnz];
+        let mut csc_values = vec![0.0; nnz];
+        for (row, bounds) in row_start.windows(2).enumerate() {
+            for k in bounds[0]..bounds[1] {
+                let pos = &mut next[col_indices[k]];
+                row_indices[*pos] = row;
+                csc_values[*pos] = values[k];
+                *pos += 1;
+            }
+        }
+
+        CscMatrix {
+            col_start,
+            row_indices,
+            values: csc_values,
+        }
+    }
+
+    /// Number of columns.
+    pub fn num_cols(&self) -> usize {
+        self.col_start.len().saturating_sub(1)
+    }
+
+    /// Returns the nonzero entries `(row, value)` of the given column.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let start = self.col_start[col];
+        let end = self.col_start[col + 1];
+        (start..end).map(move |k| (self.row_indices[k], self.values[k]))
+    }
+}
+
+/// A snapshot of the original problem, as passed to
+/// [`Presolver::presolve`](crate::presolver::Presolver::presolve).
+///
+/// It has the same layout as [`ReducedProblem`], indexed by *original* column
+/// and row indices, and additionally carries the column and row names.
+///
+/// Infinite bounds and row sides are represented as `f64::INFINITY` /
+/// `f64::NEG_INFINITY`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OriginalProblem {
+    /// Number of columns (variables) in the original problem.
+    pub num_cols: usize,
+    /// Number of rows (constraints) in the original problem.
+    pub num_rows: usize,
+    /// Lower bound of each column (`f64::NEG_INFINITY` if unbounded below).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub col_lower: Vec<f64>,
+    /// Upper bound of each column (`f64::INFINITY` if unbounded above).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub col_upper: Vec<f64>,
+    /// Whether each column is integer-constrained.
+    pub col_integral: Vec<bool>,
+    /// Objective coefficient of each column.
+    pub objective: Vec<f64>,
+    /// Name of each column.
+    pub col_names: Vec<String>,
+    /// Left-hand side of each row (`f64::NEG_INFINITY` if none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub row_lhs: Vec<f64>,
+    /// Right-hand side of each row (`f64::INFINITY` if none).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub row_rhs: Vec<f64>,
+    /// Name of each row.
+    pub row_names: Vec<String>,
+    /// CSR row pointers; `row_start[r]..row_start[r + 1]` indexes into
+    /// [`col_indices`](Self::col_indices) / [`values`](Self::values) for row `r`.
+    /// Has length `num_rows + 1`.
+    pub row_start: Vec<usize>,
+    /// Column index of each nonzero, in CSR order. Within a row, column
+    /// indices are increasing.
+    pub col_indices: Vec<usize>,
+    /// Value of each nonzero, in CSR order.
+    pub values: Vec<f64>,
+}
+
+impl OriginalProblem {
+    /// Number of nonzero entries in the constraint matrix.
+    pub fn num_nonzeros(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the nonzero entries `(column, value)` of the given original row.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let start = self.row_start[row];
+        let end = self.row_start[row + 1];
+        (start..end).map(move |k| (self.col_indices[k], self.values[k]))
+    }
 }
 
 /// Error returned when [`Presolver::postsolve`](crate::presolver::Presolver::postsolve) fails.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PostsolveError {
     /// The provided reduced solution did not have the expected length.
@@ -120,12 +370,61 @@ impl std::fmt::Display for PostsolveError {
 
 impl std::error::Error for PostsolveError {}
 
-/// Error returned when
+/// Error returned when [`Presolver::restart`](crate::presolver::Presolver::restart)
+/// cannot presolve the reduced problem again.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestartError {
+    /// The previous round did not end with [`PresolveStatus::Unchanged`] or
+    /// [`PresolveStatus::Reduced`], so there is no reduced problem to restart
+    /// from.
+    NotPresolved(PresolveStatus),
+    /// A bound change refers to a column that is not a reduced column.
+    UnknownCol {
+        /// The column index of the bound change.
+        col: usize,
+        /// The number of reduced columns.
+        num_cols: usize,
+    },
+    /// Intersecting the bounds of a column left its domain empty.
+    EmptyDomain {
+        /// The reduced column.
+        col: usize,
+        /// The resulting lower bound.
+        lower: f64,
+        /// The resulting upper bound.
+        upper: f64,
+    },
+}
+
+impl std::fmt::Display for RestartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestartError::NotPresolved(status) => {
+                write!(f, "cannot restart after presolve status {status:?}")
+            }
+            RestartError::UnknownCol { col, num_cols } => write!(
+                f,
+                "column {col} out of range for a reduced problem with {num_cols} columns"
+            ),
+            RestartError::EmptyDomain { col, lower, upper } => write!(
+                f,
+                "bounds of column {col} become empty: lower {lower} > upper {upper}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RestartError {}
+
+/// Error returned when a solution of the wrong length is passed, e.g. to
 /// [`Presolver::transform_solution`](crate::presolver::Presolver::transform_solution)
-/// is given a solution of the wrong length.
+/// or [`ReducedProblem::objective_value`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WrongLength {
-    /// The number of values that were expected (the original column count).
+    /// The number of values that were expected (the column count of the
+    /// problem the solution belongs to).
     pub expected: usize,
     /// The number of values that were provided.
     pub got: usize,
@@ -142,3 +441,196 @@ impl std::fmt::Display for WrongLength {
 }
 
 impl std::error::Error for WrongLength {}
+
+/// Tolerances used when checking a solution for feasibility.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerances {
+    /// Maximum absolute violation of a bound or row side that is accepted.
+    pub feasibility: f64,
+    /// Maximum distance to the nearest integer accepted for integer columns.
+    pub integrality: f64,
+}
+
+impl Default for Tolerances {
+    /// PaPILO's default feasibility tolerance (`numerics.feastol`) for both.
+    fn default() -> Self {
+        Tolerances {
+            feasibility: 1e-6,
+            integrality: 1e-6,
+        }
+    }
+}
+
+/// Result of checking a solution against a problem, as returned by
+/// [`Presolver::postsolve_checked`](crate::presolver::Presolver::postsolve_checked)
+/// for the original problem and by [`ReducedProblem::check_feasibility`] for
+/// the reduced one.
+///
+/// Violations are absolute. The `max_*` fields report the largest violation
+/// found, even if it is within tolerance; the index lists only contain the
+/// columns and rows whose violation exceeds the tolerance.
+/// Non-finite solution values and row activities count as infinite
+/// violations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeasibilityReport {
+    /// Objective value of the solution.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub objective: f64,
+    /// Largest violation of a column bound.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub max_bound_violation: f64,
+    /// Largest distance of an integer column's value to the nearest integer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub max_integrality_violation: f64,
+    /// Largest violation of a row's left- or right-hand side.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub max_row_violation: f64,
+    /// Columns whose bounds are violated.
+    pub violated_cols: Vec<usize>,
+    /// Integer columns with a fractional value.
+    pub fractional_cols: Vec<usize>,
+    /// Rows whose sides are violated.
+    pub violated_rows: Vec<usize>,
+}
+
+impl FeasibilityReport {
+    /// Whether the solution satisfies all bounds, integrality requirements and
+    /// rows within the tolerances it was checked with.
+    pub fn is_feasible(&self) -> bool {
+        self.violated_cols.is_empty()
+            && self.fractional_cols.is_empty()
+            && self.violated_rows.is_empty()
+    }
+
+    /// Largest violation of any kind: bound, integrality or row.
+    pub fn max_violation(&self) -> f64 {
+        self.max_bound_violation
+            .max(self.max_integrality_violation)
+            .max(self.max_row_violation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// min x + 2y + 1 ; x in [0, 4] integer, y in [0, 3] ;
+    /// 1 <= x + y <= 5 ; x - y >= 0.
+    fn reduced() -> ReducedProblem {
+        ReducedProblem {
+            num_cols: 2,
+            num_rows: 2,
+            col_lower: vec![0.0, 0.0],
+            col_upper: vec![4.0, 3.0],
+            col_integral: vec![true, false],
+            objective: vec![1.0, 2.0],
+            objective_offset: 1.0,
+            row_lhs: vec![1.0, 0.0],
+            row_rhs: vec![5.0, f64::INFINITY],
+            row_start: vec![0, 2, 4],
+            col_indices: vec![0, 1, 0, 1],
+            values: vec![1.0, 1.0, 1.0, -1.0],
+            orig_col: vec![0, 1],
+            orig_row: vec![0, 1],
+        }
+    }
+
+    #[test]
+    fn row_activities_and_slacks() {
+        let reduced = reduced();
+
+        assert_eq!(reduced.row_activities(&[2.0, 1.5]).unwrap(), vec![3.5, 0.5]);
+        assert_eq!(
+            reduced.slacks(&[2.0, 1.5]).unwrap(),
+            vec![(2.5, 1.5), (0.5, f64::INFINITY)]
+        );
+        assert_eq!(
+            reduced.slacks(&[0.0, 0.5]).unwrap(),
+            vec![(-0.5, 4.5), (-0.5, f64::INFINITY)]
+        );
+    }
+
+    #[test]
+    fn checks_feasibility_of_reduced_solutions() {
+        let reduced = reduced();
+        let tolerances = Tolerances::default();
+
+        assert!(reduced.is_feasible(&[2.0, 1.5], &tolerances).unwrap());
+        assert_eq!(reduced.max_violation(&[2.0, 1.5]).unwrap(), 0.0);
+
+        let report = reduced.check_feasibility(&[2.5, 3.25], &tolerances).unwrap();
+        assert_eq!(report.objective, 10.0);
+        assert_eq!(report.violated_cols, vec![1]);
+        assert_eq!(report.fractional_cols, vec![0]);
+        assert_eq!(report.violated_rows, vec![0, 1]);
+        assert_eq!(report.max_violation(), 0.75);
+        assert_eq!(reduced.max_violation(&[2.5, 3.25]).unwrap(), 0.75);
+        assert!(!reduced.is_feasible(&[2.5, 3.25], &tolerances).unwrap());
+
+        // Within tolerance.
+        let loose = Tolerances {
+            feasibility: 1.0,
+            integrality: 0.5,
+        };
+        assert!(reduced.is_feasible(&[2.5, 3.25], &loose).unwrap());
+    }
+
+    #[test]
+    fn csc_matches_csr() {
+        let csc = reduced().to_csc();
+
+        assert_eq!(csc.num_cols(), 2);
+        assert_eq!(csc.col(0).collect::<Vec<_>>(), vec![(0, 1.0), (1, 1.0)]);
+        assert_eq!(csc.col(1).collect::<Vec<_>>(), vec![(0, 1.0), (1, -1.0)]);
+        assert_eq!(CscMatrix::default().num_cols(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row_start must start at 0")]
+    fn csc_rejects_short_row_start() {
+        CscMatrix::from_csr(2, &[0, 1], &[0, 1], &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn csc_rejects_mismatched_values() {
+        CscMatrix::from_csr(2, &[0, 2], &[0, 1], &[1.0]);
+    }
+
+    #[test]
+    fn non_finite_values_are_violations() {
+        let reduced = reduced();
+        let tolerances = Tolerances::default();
+
+        let report = reduced.check_feasibility(&[f64::NAN, 1.0], &tolerances).unwrap();
+        assert_eq!(report.violated_cols, vec![0]);
+        assert_eq!(report.fractional_cols, vec![0]);
+        assert_eq!(report.violated_rows, vec![0, 1]);
+        assert_eq!(report.max_bound_violation, f64::INFINITY);
+        assert_eq!(report.max_integrality_violation, f64::INFINITY);
+        assert_eq!(report.max_row_violation, f64::INFINITY);
+        assert!(!reduced.is_feasible(&[f64::NAN, 1.0], &tolerances).unwrap());
+        assert_eq!(reduced.max_violation(&[f64::NAN, 1.0]).unwrap(), f64::INFINITY);
+
+        let report = reduced.check_feasibility(&[1.0, f64::INFINITY], &tolerances).unwrap();
+        assert_eq!(report.violated_cols, vec![1]);
+        assert!(report.fractional_cols.is_empty());
+        assert_eq!(report.violated_rows, vec![0, 1]);
+    }
+
+    #[test]
+    fn reduced_checks_reject_wrong_length() {
+        let reduced = reduced();
+        let err = WrongLength {
+            expected: 2,
+            got: 1,
+        };
+
+        assert_eq!(reduced.row_activities(&[1.0]), Err(err));
+        assert_eq!(reduced.slacks(&[1.0]), Err(err));
+        assert_eq!(reduced.max_violation(&[1.0]), Err(err));
+        assert_eq!(reduced.is_feasible(&[1.0], &Tolerances::default()), Err(err));
+    }
+}
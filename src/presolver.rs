@@ -1,7 +1,13 @@
 use crate::ffi;
+use crate::numerics::NumericsThresholds;
 use crate::param::{ParamResult, Parameter};
-use crate::presolve::{PostsolveError, PresolveStatus, ReducedProblem, WrongLength};
+use crate::presolve::{
+    FeasibilityReport, OriginalProblem, PostsolveError, PresolveStatus, ReducedProblem,
+    RestartError, Tolerances, WrongLength,
+};
 use crate::problem::Problem;
+use crate::report::{PresolveReport, ProblemSize};
+use std::time::{Duration, Instant};
 
 /// Convert a value using PaPILO's infinity sentinel into a Rust `f64`,
 /// mapping `>= infinity` to `f64::INFINITY` and `<= -infinity` to
@@ -21,22 +27,86 @@ fn from_papilo_inf(value: f64, infinity: f64) -> f64 {
 /// A `Presolver` runs PaPILO's presolving on a [`Problem`], exposes the reduced
 /// problem, and maps solutions of the reduced problem back to the original
 /// variable space. It does not solve the problem itself — solve the reduced
-/// problem with a solver of your choice, then call [`postsolve`](Self::postsolve).
-pub struct Presolver {
-    raw: *mut ffi::Papilo_Presolver,
+/// problem with a solver of your choice, then call
+/// [`postsolve`](Presolver::postsolve).
+///
+/// The type parameter tracks whether presolving has run. A new presolver is a
+/// `Presolver<Unpresolved>`, on which parameters can be set;
+/// [`presolve`](Presolver::presolve) consumes it and returns a
+/// `Presolver<Presolved>`, which is the only state that gives access to the
+/// reduced problem and to postsolving:
+///
+/// ```compile_fail
+/// use papilo_rs::presolver::Presolver;
+///
+/// let presolver = Presolver::new();
+/// let reduced = presolver.reduced_problem(); // not presolved yet
+/// ```
+pub struct Presolver<S = Unpresolved> {
+    raw: RawPresolver,
+    /// Numerics thresholds following the parameters set on this presolver.
+    thresholds: NumericsThresholds,
+    state: S,
 }
 
-impl Presolver {
+/// State of a [`Presolver`] that has not presolved a problem yet.
+#[derive(Debug)]
+pub struct Unpresolved;
+
+/// State of a [`Presolver`] after [`presolve`](Presolver::presolve).
+pub struct Presolved {
+    status: PresolveStatus,
+    /// The problem passed to [`presolve`](Presolver::presolve), with the
+    /// matrix in CSR form.
+    original: OriginalProblem,
+    /// The previous presolve round, if this one was started by
+    /// [`restart`](Presolver::restart). Its reduced problem is the problem
+    /// this round presolved.
+    previous: Option<Box<Presolver<Presolved>>>,
+    /// Time spent in PaPILO's presolve routine in this round.
+    time: Duration,
+}
+
+/// Owns the underlying `Papilo_Presolver` and frees it on drop, so that the
+/// state of a [`Presolver`] can change without freeing it.
+struct RawPresolver(*mut ffi::Papilo_Presolver);
+
+impl Drop for RawPresolver {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::papilo_presolver_free(self.0);
+        }
+    }
+}
+
+impl<S> Presolver<S> {
     /// Returns a raw pointer to the underlying `Papilo_Presolver`.
     pub fn raw(&self) -> *mut ffi::Papilo_Presolver {
-        self.raw
+        self.raw.0
     }
 
+    /// Thresholds for the numerics checks, such as
+    /// [`ReducedProblem::numerics_report`], that match this presolver's
+    /// parameters: `numerics.epsilon` gives [`tiny`](NumericsThresholds::tiny),
+    /// `numerics.hugeval` gives [`huge`](NumericsThresholds::huge) and
+    /// `numerics.feastol` gives [`parallel`](NumericsThresholds::parallel).
+    /// Parameters that were not set with [`set_param`](Presolver::set_param)
+    /// keep their defaults.
+    pub fn numerics_thresholds(&self) -> NumericsThresholds {
+        self.thresholds
+    }
+}
+
+impl Presolver<Unpresolved> {
     /// Creates a new presolver with the default presolvers enabled.
     pub fn new() -> Self {
         let raw = unsafe { ffi::papilo_presolver_create() };
         assert!(!raw.is_null(), "Failed to create a new Presolver instance");
-        let mut presolver = Self { raw };
+        let mut presolver = Self {
+            raw: RawPresolver(raw),
+            thresholds: NumericsThresholds::default(),
+            state: Unpresolved,
+        };
         // Quiet by default; ignore if the parameter is unavailable.
         let _ = presolver.set_param("message.verbosity", 0);
         presolver
@@ -47,48 +117,301 @@ impl Presolver {
         P::set(self, key, value)
     }
 
-    /// Runs presolving on the given problem and returns the resulting status.
+    /// Updates the numerics thresholds after the real parameter `key` was set
+    /// to `value`.
+    pub(crate) fn record_real_param(&mut self, key: &str, value: f64) {
+        match key {
+            "numerics.epsilon" => self.thresholds.tiny = value,
+            "numerics.hugeval" => self.thresholds.huge = value,
+            "numerics.feastol" => self.thresholds.parallel = value,
+            _ => {}
+        }
+    }
+
+    /// Runs presolving on the given problem.
     ///
-    /// The problem is consumed: afterwards, the reduced problem can be inspected
-    /// with [`reduced_problem`](Self::reduced_problem) and solutions of the
-    /// reduced problem can be mapped back with [`postsolve`](Self::postsolve).
-    pub fn presolve(&mut self, problem: Problem) -> PresolveStatus {
-        unsafe { ffi::papilo_presolver_load_problem(self.raw, problem.raw()) };
+    /// Both the presolver and the problem are consumed. The returned
+    /// presolver reports the resulting [`status`](Presolver::status), exposes
+    /// the reduced problem through
+    /// [`reduced_problem`](Presolver::reduced_problem), and maps solutions of
+    /// the reduced problem back with [`postsolve`](Presolver::postsolve).
+    pub fn presolve(self, mut problem: Problem) -> Presolver<Presolved> {
+        let original = problem.take_data().to_original();
+        unsafe { ffi::papilo_presolver_load_problem(self.raw(), problem.raw()) };
         // `problem` is dropped here, freeing the now-empty C problem.
-        unsafe { ffi::papilo_presolver_presolve(self.raw) }.into()
+        let start = Instant::now();
+        let status = unsafe { ffi::papilo_presolver_presolve(self.raw()) }.into();
+        let time = start.elapsed();
+        Presolver {
+            raw: self.raw,
+            thresholds: self.thresholds,
+            state: Presolved {
+                status,
+                original,
+                previous: None,
+                time,
+            },
+        }
+    }
+}
+
+impl Presolver<Presolved> {
+    /// The status returned by presolving.
+    ///
+    /// After a [`restart`](Self::restart), this is the status of the last
+    /// round only; see [`statuses`](Self::statuses) for all rounds.
+    pub fn status(&self) -> PresolveStatus {
+        self.state.status
+    }
+
+    /// The status of every presolve round, oldest first. Without a
+    /// [`restart`](Self::restart), this is just [`status`](Self::status).
+    pub fn statuses(&self) -> Vec<PresolveStatus> {
+        let mut statuses = match &self.state.previous {
+            Some(previous) => previous.statuses(),
+            None => Vec::new(),
+        };
+        statuses.push(self.state.status);
+        statuses
+    }
+
+    /// Presolves the reduced problem again after tightening column bounds,
+    /// e.g. with global fixings found by a solver, as in a restart.
+    ///
+    /// `bound_changes` holds `(reduced column, lower, upper)` triples; each is
+    /// intersected with the current bounds of that column. The reduced
+    /// problem is presolved by `presolver`, which may use different
+    /// parameters. The returned presolver composes both rounds: its reduced
+    /// problem is the result of the second round, while
+    /// [`postsolve`](Self::postsolve),
+    /// [`transform_solution`](Self::transform_solution), the mappings in
+    /// [`ReducedProblem`] and the objective offset all refer to the original
+    /// problem.
+    ///
+    /// Returns an error, dropping this presolver, if the last round's
+    /// [`status`](Self::status) is neither `Unchanged` nor `Reduced`, if a
+    /// column index in `bound_changes` is not a reduced column, or if the
+    /// bounds of a column become empty.
+    pub fn restart(
+        mut self,
+        bound_changes: &[(usize, f64, f64)],
+        presolver: Presolver,
+    ) -> Result<Presolver<Presolved>, RestartError> {
+        match self.state.status {
+            PresolveStatus::Unchanged | PresolveStatus::Reduced => {}
+            status => return Err(RestartError::NotPresolved(status)),
+        }
+        let mut reduced = self.reduced_problem();
+        for &(col, lower, upper) in bound_changes {
+            let num_cols = reduced.num_cols;
+            let (Some(col_lower), Some(col_upper)) =
+                (reduced.col_lower.get_mut(col), reduced.col_upper.get_mut(col))
+            else {
+                return Err(RestartError::UnknownCol { col, num_cols });
+            };
+            *col_lower = col_lower.max(lower);
+            *col_upper = col_upper.min(upper);
+            if *col_lower > *col_upper {
+                return Err(RestartError::EmptyDomain {
+                    col,
+                    lower: *col_lower,
+                    upper: *col_upper,
+                });
+            }
+        }
+
+        let problem =
+            Problem::from_reduced(&reduced).expect("extracted reduced problem is consistent");
+        let mut restarted = presolver.presolve(problem);
+        restarted.state.original = std::mem::take(&mut self.state.original);
+        restarted.state.previous = Some(Box::new(self));
+        Ok(restarted)
     }
 
     /// The value of infinity the loaded problem was created with. Bounds and row
     /// sides with absolute value at least this large are treated as infinite.
     pub fn infinity(&self) -> f64 {
-        unsafe { ffi::papilo_presolver_get_infinity(self.raw) }
+        unsafe { ffi::papilo_presolver_get_infinity(self.raw()) }
     }
 
     /// Number of columns (variables) in the original (loaded) problem.
     pub fn num_cols(&self) -> usize {
-        unsafe { ffi::papilo_presolver_get_num_cols_original(self.raw) as usize }
+        match &self.state.previous {
+            Some(previous) => previous.num_cols(),
+            None => self.round_num_cols(),
+        }
     }
 
     /// Number of rows (constraints) in the original (loaded) problem.
     pub fn num_rows(&self) -> usize {
-        unsafe { ffi::papilo_presolver_get_num_rows_original(self.raw) as usize }
+        match &self.state.previous {
+            Some(previous) => previous.num_rows(),
+            None => unsafe {
+                ffi::papilo_presolver_get_num_rows_original(self.raw()) as usize
+            },
+        }
+    }
+
+    /// Number of columns of the problem presolved in this round, which is the
+    /// previous round's reduced problem after a [`restart`](Self::restart).
+    fn round_num_cols(&self) -> usize {
+        unsafe { ffi::papilo_presolver_get_num_cols_original(self.raw()) as usize }
+    }
+
+    /// Returns a snapshot of the original problem passed to
+    /// [`presolve`](Presolver::presolve): bounds, objective, constraint
+    /// matrix, names and integrality.
+    pub fn original_problem(&self) -> OriginalProblem {
+        self.state.original.clone()
     }
 
-    /// Extracts the reduced problem produced by [`presolve`](Self::presolve).
+    /// Objective value of a solution of the original problem.
     ///
-    /// This must be called after [`presolve`](Self::presolve); otherwise the
-    /// returned data is unspecified.
+    /// `original` must have one entry per original column (see
+    /// [`num_cols`](Self::num_cols)). After postsolving a reduced solution `z`,
+    /// this agrees with [`ReducedProblem::objective_value`] evaluated at `z`
+    /// up to numerical tolerances.
+    pub fn original_objective(&self, original: &[f64]) -> Result<f64, WrongLength> {
+        let expected = self.state.original.num_cols;
+        if original.len() != expected {
+            return Err(WrongLength {
+                expected,
+                got: original.len(),
+            });
+        }
+        Ok(self.state.original.model().objective_value(original))
+    }
+
+    /// Converts an objective value of the original problem, such as an
+    /// incumbent cutoff or a dual bound, into the value a solver reports for
+    /// the reduced problem.
+    ///
+    /// A solver given [`ReducedProblem::objective`] does not know about
+    /// [`ReducedProblem::objective_offset`], so its values exclude the offset,
+    /// unlike [`ReducedProblem::objective_value`]. Both problems are minimized
+    /// and presolving does not scale the objective, so the conversion is a
+    /// shift by the offset; infinite bounds stay infinite.
+    pub fn original_to_reduced_solver_value(&self, original_value: f64) -> f64 {
+        original_value - self.objective_offset()
+    }
+
+    /// Converts an objective value reported by a solver for the reduced
+    /// problem, i.e. excluding [`ReducedProblem::objective_offset`], into the
+    /// original problem's objective. This is the inverse of
+    /// [`original_to_reduced_solver_value`](Self::original_to_reduced_solver_value).
+    ///
+    /// Values from [`ReducedProblem::objective_value`] already include the
+    /// offset and are original objective values as they are.
+    pub fn reduced_solver_value_to_original(&self, solver_value: f64) -> f64 {
+        solver_value + self.objective_offset()
+    }
+
+    /// The reduced problem's constant objective offset, without extracting the
+    /// whole reduced problem.
+    fn objective_offset(&self) -> f64 {
+        let offset = unsafe { ffi::papilo_presolver_get_reduced_obj_offset(self.raw()) };
+        match &self.state.previous {
+            Some(previous) => offset + previous.objective_offset(),
+            None => offset,
+        }
+    }
+
+    /// For each reduced column, the index of the original column.
+    fn orig_col_mapping(&self) -> Vec<usize> {
+        let num_cols =
+            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw()) } as usize;
+        let mut orig_col_raw = vec![0i32; num_cols];
+        unsafe {
+            ffi::papilo_presolver_get_orig_col_mapping(self.raw(), orig_col_raw.as_mut_ptr())
+        };
+        let orig_col = orig_col_raw.into_iter().map(|i| i as usize);
+        match &self.state.previous {
+            Some(previous) => {
+                let previous_orig_col = previous.orig_col_mapping();
+                orig_col.map(|i| previous_orig_col[i]).collect()
+            }
+            None => orig_col.collect(),
+        }
+    }
+
+    /// For each reduced row, the index of the original row.
+    fn orig_row_mapping(&self) -> Vec<usize> {
+        let num_rows =
+            unsafe { ffi::papilo_presolver_get_reduced_num_rows(self.raw()) } as usize;
+        let mut orig_row_raw = vec![0i32; num_rows];
+        unsafe {
+            ffi::papilo_presolver_get_orig_row_mapping(self.raw(), orig_row_raw.as_mut_ptr())
+        };
+        let orig_row = orig_row_raw.into_iter().map(|i| i as usize);
+        match &self.state.previous {
+            Some(previous) => {
+                let previous_orig_row = previous.orig_row_mapping();
+                orig_row.map(|i| previous_orig_row[i]).collect()
+            }
+            None => orig_row.collect(),
+        }
+    }
+
+    /// Summarizes the effect of presolving: original and reduced problem size,
+    /// objective offset, status, and the time spent presolving, including
+    /// restarts. See [`PresolveReport`] for the available output formats.
+    pub fn report(&self) -> PresolveReport {
+        let original = &self.state.original;
+        let num_cols =
+            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw()) } as usize;
+        let num_rows =
+            unsafe { ffi::papilo_presolver_get_reduced_num_rows(self.raw()) } as usize;
+        let nnz =
+            unsafe { ffi::papilo_presolver_get_reduced_num_nonzeros(self.raw()) } as usize;
+        let mut integral_raw = vec![0u8; num_cols];
+        unsafe {
+            ffi::papilo_presolver_get_reduced_col_integral(self.raw(), integral_raw.as_mut_ptr())
+        };
+
+        let mut restarts = 0;
+        let mut time = self.state.time;
+        let mut previous = &self.state.previous;
+        while let Some(round) = previous {
+            restarts += 1;
+            time += round.state.time;
+            previous = &round.state.previous;
+        }
+
+        PresolveReport {
+            status: self.state.status,
+            original: ProblemSize {
+                rows: original.num_rows,
+                cols: original.num_cols,
+                nonzeros: original.values.len(),
+                integers: original.col_integral.iter().filter(|&&i| i).count(),
+            },
+            reduced: ProblemSize {
+                rows: num_rows,
+                cols: num_cols,
+                nonzeros: nnz,
+                integers: integral_raw.iter().filter(|&&b| b != 0).count(),
+            },
+            objective_offset: self.objective_offset(),
+            restarts,
+            time,
+        }
+    }
+
+    /// Extracts the reduced problem produced by [`presolve`](Presolver::presolve).
     pub fn reduced_problem(&self) -> ReducedProblem {
         let inf = self.infinity();
-        let num_cols = unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw) } as usize;
-        let num_rows = unsafe { ffi::papilo_presolver_get_reduced_num_rows(self.raw) } as usize;
-        let nnz = unsafe { ffi::papilo_presolver_get_reduced_num_nonzeros(self.raw) } as usize;
+        let num_cols =
+            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw()) } as usize;
+        let num_rows =
+            unsafe { ffi::papilo_presolver_get_reduced_num_rows(self.raw()) } as usize;
+        let nnz =
+            unsafe { ffi::papilo_presolver_get_reduced_num_nonzeros(self.raw()) } as usize;
 
         let mut col_lower = vec![0.0; num_cols];
         let mut col_upper = vec![0.0; num_cols];
         unsafe {
             ffi::papilo_presolver_get_reduced_col_bounds(
-                self.raw,
+                self.raw(),
                 col_lower.as_mut_ptr(),
                 col_upper.as_mut_ptr(),
             );
@@ -101,13 +424,12 @@ impl Presolver {
         }
 
         let mut objective = vec![0.0; num_cols];
-        unsafe { ffi::papilo_presolver_get_reduced_obj(self.raw, objective.as_mut_ptr()) };
-        let objective_offset =
-            unsafe { ffi::papilo_presolver_get_reduced_obj_offset(self.raw) };
+        unsafe { ffi::papilo_presolver_get_reduced_obj(self.raw(), objective.as_mut_ptr()) };
+        let objective_offset = self.objective_offset();
 
         let mut integral_raw = vec![0u8; num_cols];
         unsafe {
-            ffi::papilo_presolver_get_reduced_col_integral(self.raw, integral_raw.as_mut_ptr())
+            ffi::papilo_presolver_get_reduced_col_integral(self.raw(), integral_raw.as_mut_ptr())
         };
         let col_integral = integral_raw.into_iter().map(|b| b != 0).collect();
 
@@ -115,7 +437,7 @@ impl Presolver {
         let mut row_rhs = vec![0.0; num_rows];
         unsafe {
             ffi::papilo_presolver_get_reduced_row_sides(
-                self.raw,
+                self.raw(),
                 row_lhs.as_mut_ptr(),
                 row_rhs.as_mut_ptr(),
             );
@@ -132,7 +454,7 @@ impl Presolver {
         let mut values = vec![0.0; nnz];
         unsafe {
             ffi::papilo_presolver_get_reduced_matrix_csr(
-                self.raw,
+                self.raw(),
                 row_start_raw.as_mut_ptr(),
                 col_indices_raw.as_mut_ptr(),
                 values.as_mut_ptr(),
@@ -141,17 +463,8 @@ impl Presolver {
         let row_start = row_start_raw.into_iter().map(|i| i as usize).collect();
         let col_indices = col_indices_raw.into_iter().map(|i| i as usize).collect();
 
-        let mut orig_col_raw = vec![0i32; num_cols];
-        unsafe {
-            ffi::papilo_presolver_get_orig_col_mapping(self.raw, orig_col_raw.as_mut_ptr())
-        };
-        let orig_col = orig_col_raw.into_iter().map(|i| i as usize).collect();
-
-        let mut orig_row_raw = vec![0i32; num_rows];
-        unsafe {
-            ffi::papilo_presolver_get_orig_row_mapping(self.raw, orig_row_raw.as_mut_ptr())
-        };
-        let orig_row = orig_row_raw.into_iter().map(|i| i as usize).collect();
+        let orig_col = self.orig_col_mapping();
+        let orig_row = self.orig_row_mapping();
 
         ReducedProblem {
             num_cols,
@@ -177,11 +490,9 @@ impl Presolver {
     /// `reduced_solution` must have one entry per reduced column (see
     /// [`ReducedProblem::num_cols`]). On success, returns a solution with one
     /// entry per original column (see [`num_cols`](Self::num_cols)).
-    ///
-    /// This must be called after [`presolve`](Self::presolve).
     pub fn postsolve(&self, reduced_solution: &[f64]) -> Result<Vec<f64>, PostsolveError> {
         let expected =
-            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw) } as usize;
+            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw()) } as usize;
         if reduced_solution.len() != expected {
             return Err(PostsolveError::WrongLength {
                 expected,
@@ -189,20 +500,40 @@ impl Presolver {
             });
         }
 
-        let mut original = vec![0.0; self.num_cols()];
+        let mut original = vec![0.0; self.round_num_cols()];
         let status = unsafe {
             ffi::papilo_presolver_postsolve(
-                self.raw,
+                self.raw(),
                 reduced_solution.as_ptr(),
                 original.as_mut_ptr(),
             )
         };
 
-        if status == ffi::Papilo_PostsolveStatus_PAPILO_POSTSOLVE_STATUS_OK {
-            Ok(original)
-        } else {
-            Err(PostsolveError::Failed)
+        if status != ffi::Papilo_PostsolveStatus_PAPILO_POSTSOLVE_STATUS_OK {
+            return Err(PostsolveError::Failed);
         }
+        match &self.state.previous {
+            Some(previous) => previous.postsolve(&original),
+            None => Ok(original),
+        }
+    }
+
+    /// Like [`postsolve`](Self::postsolve), but additionally checks the
+    /// resulting solution against the original problem.
+    ///
+    /// The returned [`FeasibilityReport`] lists the violated column bounds,
+    /// fractional integer columns and violated rows of the original problem,
+    /// together with the original objective value. A solution that is
+    /// infeasible in the original problem is still returned; use
+    /// [`FeasibilityReport::is_feasible`] to decide whether to accept it.
+    pub fn postsolve_checked(
+        &self,
+        reduced_solution: &[f64],
+        tolerances: &Tolerances,
+    ) -> Result<(Vec<f64>, FeasibilityReport), PostsolveError> {
+        let original = self.postsolve(reduced_solution)?;
+        let report = self.state.original.model().check_feasibility(&original, tolerances);
+        Ok((original, report))
     }
 
     /// Maps a primal solution of the original problem **forward** to the reduced
@@ -217,8 +548,6 @@ impl Presolver {
     /// combination of original variables). For a feasible solution of the
     /// original problem this yields the corresponding reduced solution; in
     /// particular `transform_solution(postsolve(z)) == z`.
-    ///
-    /// This must be called after [`presolve`](Self::presolve).
     pub fn transform_solution(&self, original: &[f64]) -> Result<Vec<f64>, WrongLength> {
         let expected = self.num_cols();
         if original.len() != expected {
@@ -228,13 +557,22 @@ impl Presolver {
             });
         }
 
+        let intermediate;
+        let round_input = match &self.state.previous {
+            Some(previous) => {
+                intermediate = previous.transform_solution(original)?;
+                &intermediate[..]
+            }
+            None => original,
+        };
+
         let num_reduced =
-            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw) } as usize;
+            unsafe { ffi::papilo_presolver_get_reduced_num_cols(self.raw()) } as usize;
         let mut reduced = vec![0.0; num_reduced];
         unsafe {
             ffi::papilo_presolver_transform_solution(
-                self.raw,
-                original.as_ptr(),
+                self.raw(),
+                round_input.as_ptr(),
                 reduced.as_mut_ptr(),
             );
         }
@@ -248,22 +586,15 @@ impl Default for Presolver {
     }
 }
 
-impl Drop for Presolver {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::papilo_presolver_free(self.raw);
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::problem::MalformedProblem;
 
     #[test]
     fn presolver_creation() {
         let presolver = Presolver::new();
-        assert!(!presolver.raw.is_null());
+        assert!(!presolver.raw().is_null());
     }
 
     #[test]
@@ -273,8 +604,8 @@ mod tests {
         let x = problem.add_col(1.0, 10.0, true, 10.0, "x1");
         problem.add_row("r1", &[(x, 1.0)], 2.5, f64::INFINITY);
 
-        let mut presolver = Presolver::new();
-        let status = presolver.presolve(problem);
+        let presolver = Presolver::new().presolve(problem);
+        let status = presolver.status();
         assert_eq!(status, PresolveStatus::Reduced);
 
         assert_eq!(presolver.num_cols(), 1);
@@ -289,6 +620,39 @@ mod tests {
         assert_eq!(original, vec![3.0]);
     }
 
+    #[test]
+    fn objective_values_translate_between_spaces() {
+        // x is fixed to 3, so the whole objective 10 * x moves into the offset.
+        let mut problem = Problem::new();
+        let x = problem.add_col(1.0, 10.0, true, 10.0, "x1");
+        problem.add_row("r1", &[(x, 1.0)], 2.5, f64::INFINITY);
+
+        let presolver = Presolver::new().presolve(problem);
+        assert_eq!(presolver.reduced_solver_value_to_original(0.0), 30.0);
+        assert_eq!(presolver.original_to_reduced_solver_value(30.0), 0.0);
+        assert_eq!(
+            presolver.original_to_reduced_solver_value(f64::INFINITY),
+            f64::INFINITY
+        );
+
+        // A solver sees only the reduced objective; objective_value adds the
+        // offset itself. Both conventions meet in the original objective.
+        let reduced = presolver.reduced_problem();
+        let z: Vec<f64> = vec![];
+        let solver_value: f64 = reduced.objective.iter().zip(&z).map(|(c, x)| c * x).sum();
+        let original = presolver.postsolve(&z).unwrap();
+        let original_value = presolver.original_objective(&original).unwrap();
+        assert_eq!(
+            presolver.reduced_solver_value_to_original(solver_value),
+            original_value
+        );
+        assert_eq!(reduced.objective_value(&z).unwrap(), original_value);
+        assert_eq!(
+            presolver.original_to_reduced_solver_value(reduced.objective_value(&z).unwrap()),
+            solver_value
+        );
+    }
+
     #[test]
     fn presolve_extracts_reduced_problem() {
         // min x + y ; x,y in [0,10] ; x + 2y >= 4 ; 3x + y >= 5.
@@ -301,7 +665,8 @@ mod tests {
 
         let mut presolver = Presolver::new();
         presolver.set_param("presolve.dualreds", 0).unwrap();
-        let status = presolver.presolve(problem);
+        let presolver = presolver.presolve(problem);
+        let status = presolver.status();
         assert!(matches!(
             status,
             PresolveStatus::Unchanged | PresolveStatus::Reduced
@@ -323,10 +688,240 @@ mod tests {
         assert_eq!(row0, vec![(0, 1.0), (1, 2.0)]);
         assert_eq!(row1, vec![(0, 3.0), (1, 1.0)]);
 
+        let csc = reduced.to_csc();
+        let col0: Vec<_> = csc.col(0).collect();
+        let col1: Vec<_> = csc.col(1).collect();
+        assert_eq!(col0, vec![(0, 1.0), (1, 3.0)]);
+        assert_eq!(col1, vec![(0, 2.0), (1, 1.0)]);
+        assert_eq!(csc.num_cols(), 2);
+
         assert_eq!(reduced.orig_col, vec![0, 1]);
         assert_eq!(reduced.orig_row, vec![0, 1]);
     }
 
+    #[test]
+    fn original_problem_is_retained() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(f64::NEG_INFINITY, 5.0, true, 2.0, "y");
+        problem.add_row("r0", &[(y, 2.0), (x, 1.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0)], f64::NEG_INFINITY, 6.0);
+        problem.change_col_ub(x, 8.0);
+
+        let presolver = Presolver::new().presolve(problem);
+
+        let original = presolver.original_problem();
+        assert_eq!(original.num_cols, 2);
+        assert_eq!(original.num_rows, 2);
+        assert_eq!(original.col_lower, vec![0.0, f64::NEG_INFINITY]);
+        assert_eq!(original.col_upper, vec![8.0, 5.0]);
+        assert_eq!(original.col_integral, vec![false, true]);
+        assert_eq!(original.objective, vec![1.0, 2.0]);
+        assert_eq!(original.col_names, vec!["x", "y"]);
+        assert_eq!(original.row_names, vec!["r0", "r1"]);
+        assert_eq!(original.row_lhs, vec![4.0, f64::NEG_INFINITY]);
+        assert_eq!(original.row_rhs, vec![f64::INFINITY, 6.0]);
+        assert_eq!(original.num_nonzeros(), 3);
+
+        let row0: Vec<_> = original.row(0).collect();
+        let row1: Vec<_> = original.row(1).collect();
+        assert_eq!(row0, vec![(0, 1.0), (1, 2.0)]);
+        assert_eq!(row1, vec![(0, 3.0)]);
+    }
+
+    #[test]
+    fn restart_composes_both_rounds() {
+        // min x + y ; x,y in [0,10] ; x + 2y >= 4 ; 3x + y >= 5.
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let presolver = presolver.presolve(problem);
+        assert_eq!(presolver.reduced_problem().orig_col, vec![x, y]);
+
+        // Fix x to 2 in the reduced space and presolve again.
+        let mut second = Presolver::new();
+        second.set_param("presolve.dualreds", 0).unwrap();
+        let first_status = presolver.status();
+        let presolver = presolver.restart(&[(0, 2.0, 2.0)], second).unwrap();
+        assert_eq!(presolver.statuses(), vec![first_status, presolver.status()]);
+        assert_eq!(presolver.num_cols(), 2);
+        assert_eq!(presolver.num_rows(), 2);
+
+        let reduced = presolver.reduced_problem();
+        assert!(reduced.orig_col.iter().all(|&col| col == y));
+
+        // The composed maps go all the way back to the original problem.
+        let z = presolver.transform_solution(&[2.0, 3.0]).unwrap();
+        assert_eq!(z.len(), reduced.num_cols);
+        let original = presolver.postsolve(&z).unwrap();
+        assert_eq!(original[x], 2.0);
+        let reduced_obj = reduced.objective_value(&z).unwrap();
+        let original_obj = presolver.original_objective(&original).unwrap();
+        assert!((reduced_obj - original_obj).abs() < 1e-9);
+        assert_eq!(presolver.report().restarts, 1);
+    }
+
+    #[test]
+    fn numerics_thresholds_follow_parameters() {
+        let mut presolver = Presolver::new();
+        assert_eq!(presolver.numerics_thresholds(), NumericsThresholds::default());
+
+        presolver.set_param("numerics.hugeval", 1e10).unwrap();
+        presolver.set_param("numerics.feastol", 1e-7).unwrap();
+        assert!(presolver.set_param("numerics.hugeval", "big").is_err());
+        let presolver = presolver.presolve(Problem::new());
+
+        let thresholds = presolver.numerics_thresholds();
+        assert_eq!(thresholds.huge, 1e10);
+        assert_eq!(thresholds.parallel, 1e-7);
+        assert_eq!(thresholds.tiny, NumericsThresholds::default().tiny);
+    }
+
+    #[test]
+    fn restart_rejects_invalid_bound_changes() {
+        let presolve = || {
+            let mut problem = Problem::new();
+            let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+            let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+            problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+            problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+            let mut presolver = Presolver::new();
+            presolver.set_param("presolve.dualreds", 0).unwrap();
+            presolver.presolve(problem)
+        };
+
+        assert_eq!(
+            presolve().restart(&[(2, 0.0, 1.0)], Presolver::new()).err(),
+            Some(RestartError::UnknownCol { col: 2, num_cols: 2 })
+        );
+        assert_eq!(
+            presolve().restart(&[(0, 3.0, 2.0)], Presolver::new()).err(),
+            Some(RestartError::EmptyDomain { col: 0, lower: 3.0, upper: 2.0 })
+        );
+        assert_eq!(
+            presolve().restart(&[(1, 11.0, f64::INFINITY)], Presolver::new()).err(),
+            Some(RestartError::EmptyDomain { col: 1, lower: 11.0, upper: 10.0 })
+        );
+    }
+
+    #[test]
+    fn restart_requires_presolved_status() {
+        // x in [0, 1] with x >= 2 is infeasible.
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 1.0, false, 1.0, "x");
+        problem.add_row("r0", &[(x, 1.0)], 2.0, f64::INFINITY);
+
+        let presolver = Presolver::new().presolve(problem);
+        let status = presolver.status();
+        assert!(!matches!(
+            status,
+            PresolveStatus::Unchanged | PresolveStatus::Reduced
+        ));
+        assert_eq!(
+            presolver.restart(&[], Presolver::new()).err(),
+            Some(RestartError::NotPresolved(status))
+        );
+    }
+
+    #[test]
+    fn report_compares_original_and_reduced() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, true, 1.0, "x");
+        let y = problem.add_col(3.0, 3.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 8.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let presolver = Presolver::new().presolve(problem);
+        let report = presolver.report();
+        let reduced = presolver.reduced_problem();
+
+        assert_eq!(report.status, presolver.status());
+        assert_eq!(report.restarts, 0);
+        assert_eq!(
+            report.original,
+            ProblemSize {
+                rows: 2,
+                cols: 2,
+                nonzeros: 4,
+                integers: 1,
+            }
+        );
+        assert_eq!(report.reduced.rows, reduced.num_rows);
+        assert_eq!(report.reduced.cols, reduced.num_cols);
+        assert_eq!(report.reduced.nonzeros, reduced.num_nonzeros());
+        assert_eq!(
+            report.reduced.integers,
+            reduced.col_integral.iter().filter(|&&i| i).count()
+        );
+        assert_eq!(report.objective_offset, reduced.objective_offset);
+        assert!(report.to_json().starts_with(r#"{"status":""#));
+    }
+
+    #[test]
+    fn problem_from_reduced_round_trips() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, true, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let reduced = presolver.presolve(problem).reduced_problem();
+
+        let rebuilt = Problem::try_from(&reduced).unwrap();
+        assert_eq!(rebuilt.num_cols(), reduced.num_cols);
+        assert_eq!(rebuilt.num_rows(), reduced.num_rows);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let presolver = presolver.presolve(rebuilt);
+        let original = presolver.original_problem();
+        assert_eq!(original.col_lower, reduced.col_lower);
+        assert_eq!(original.col_integral, reduced.col_integral);
+        assert_eq!(original.row_lhs, reduced.row_lhs);
+        assert_eq!(original.col_indices, reduced.col_indices);
+        assert_eq!(original.values, reduced.values);
+    }
+
+    #[test]
+    fn problem_from_reduced_rejects_malformed_data() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let reduced = presolver.presolve(problem).reduced_problem();
+
+        let mut bad = reduced.clone();
+        bad.objective.pop();
+        assert_eq!(
+            Problem::from_reduced(&bad).err(),
+            Some(MalformedProblem::WrongLength {
+                field: "objective",
+                expected: reduced.num_cols,
+                got: reduced.num_cols - 1,
+            })
+        );
+
+        let mut bad = reduced.clone();
+        bad.col_indices[0] = reduced.num_cols;
+        assert_eq!(
+            Problem::from_reduced(&bad).err(),
+            Some(MalformedProblem::ColumnOutOfRange {
+                row: 0,
+                col: reduced.num_cols,
+            })
+        );
+    }
+
     #[test]
     fn postsolve_rejects_wrong_length() {
         let mut problem = Problem::new();
@@ -335,7 +930,7 @@ mod tests {
 
         let mut presolver = Presolver::new();
         presolver.set_param("presolve.dualreds", 0).unwrap();
-        presolver.presolve(problem);
+        let presolver = presolver.presolve(problem);
 
         let expected = presolver.reduced_problem().num_cols;
         let err = presolver.postsolve(&vec![0.0; expected + 1]).unwrap_err();
@@ -348,6 +943,15 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "row 1 out of range")]
+    fn set_row_coef_rejects_unknown_row() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 1.0, false, 1.0, "x");
+        problem.add_row("r0", &[(x, 1.0)], 0.0, 1.0);
+        problem.set_row_coef(1, x, 1.0);
+    }
+
     #[test]
     fn problem_dimension_getters() {
         let mut problem = Problem::new();
@@ -369,7 +973,7 @@ mod tests {
 
         let mut presolver = Presolver::new();
         presolver.set_param("presolve.dualreds", 0).unwrap();
-        presolver.presolve(problem);
+        let presolver = presolver.presolve(problem);
 
         let reduced = presolver.reduced_problem();
         let original = vec![2.0, 3.0];
@@ -393,8 +997,7 @@ mod tests {
         problem.add_row("r0", &[(x, 1.0), (y, 2.0), (w, 1.0)], 5.0, f64::INFINITY);
         problem.add_row("r1", &[(x, 1.0), (y, 2.0), (w, 5.0)], f64::NEG_INFINITY, 40.0);
 
-        let mut presolver = Presolver::new();
-        presolver.presolve(problem);
+        let presolver = Presolver::new().presolve(problem);
 
         let reduced = presolver.reduced_problem();
         // x and y are merged into a single reduced column (plus w).
@@ -419,14 +1022,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn objective_agrees_across_spaces() {
+        // The merged column carries the objective of both x and y.
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 2.0, "y");
+        let w = problem.add_col(0.0, 10.0, false, 1.0, "w");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0), (w, 1.0)], 5.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 1.0), (y, 2.0), (w, 5.0)], f64::NEG_INFINITY, 40.0);
+
+        let presolver = Presolver::new().presolve(problem);
+        let reduced = presolver.reduced_problem();
+
+        let z = presolver.transform_solution(&[2.0, 1.0, 1.0]).unwrap();
+        let original = presolver.postsolve(&z).unwrap();
+        let reduced_obj = reduced.objective_value(&z).unwrap();
+        let original_obj = presolver.original_objective(&original).unwrap();
+        assert!((reduced_obj - original_obj).abs() < 1e-9);
+        assert_eq!(original_obj, 5.0);
+
+        let err = presolver.original_objective(&[1.0]).unwrap_err();
+        assert_eq!(err, WrongLength { expected: 3, got: 1 });
+    }
+
+    #[test]
+    fn postsolve_checked_reports_feasible_solution() {
+        // min x + y ; x,y in [0,10] ; x + 2y >= 4 ; 3x + y >= 5.
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let presolver = presolver.presolve(problem);
+
+        let z = presolver.transform_solution(&[2.0, 3.0]).unwrap();
+        let (original, report) = presolver
+            .postsolve_checked(&z, &Tolerances::default())
+            .unwrap();
+        assert_eq!(original, vec![2.0, 3.0]);
+        assert!(report.is_feasible());
+        assert_eq!(report.objective, 5.0);
+        assert_eq!(report.max_row_violation, 0.0);
+    }
+
+    #[test]
+    fn postsolve_checked_reports_violations() {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let presolver = presolver.presolve(problem);
+
+        // x = y = 0 violates both rows.
+        let z = presolver.transform_solution(&[0.0, 0.0]).unwrap();
+        let (_, report) = presolver
+            .postsolve_checked(&z, &Tolerances::default())
+            .unwrap();
+        assert!(!report.is_feasible());
+        assert_eq!(report.violated_rows, vec![0, 1]);
+        assert_eq!(report.max_row_violation, 5.0);
+        assert!(report.violated_cols.is_empty());
+    }
+
     #[test]
     fn transform_solution_rejects_wrong_length() {
         let mut problem = Problem::new();
         let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
         problem.add_row("r0", &[(x, 1.0)], 1.0, f64::INFINITY);
 
-        let mut presolver = Presolver::new();
-        presolver.presolve(problem);
+        let presolver = Presolver::new().presolve(problem);
 
         let err = presolver.transform_solution(&[1.0, 2.0]).unwrap_err();
         assert_eq!(err, WrongLength { expected: 1, got: 2 });
@@ -1,9 +1,34 @@
 use crate::ffi;
+use crate::presolve::{OriginalProblem, ReducedProblem};
 use std::ffi::{CStr, CString};
+use std::ops::Range;
 
 /// A struct representing a problem in the Papilo library.
+///
+/// Besides the PaPILO problem, a `Problem` keeps a Rust-side copy of everything
+/// added through its methods, so that the original model remains available
+/// after it is handed to a [`Presolver`](crate::presolver::Presolver).
+/// Changes made directly through [`raw`](Self::raw) are not reflected in that
+/// copy.
 pub struct Problem {
     raw: *mut ffi::Papilo_Problem,
+    data: ProblemData,
+}
+
+/// Rust-side copy of the data added to a [`Problem`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProblemData {
+    pub(crate) col_lower: Vec<f64>,
+    pub(crate) col_upper: Vec<f64>,
+    pub(crate) col_integral: Vec<bool>,
+    pub(crate) objective: Vec<f64>,
+    pub(crate) col_names: Vec<String>,
+    pub(crate) row_lhs: Vec<f64>,
+    pub(crate) row_rhs: Vec<f64>,
+    pub(crate) row_names: Vec<String>,
+    /// Nonzeros `(row, column, value)` in the order they were set. Setting a
+    /// coefficient twice keeps the last value, see [`csr`](Self::csr).
+    pub(crate) entries: Vec<(usize, usize, f64)>,
 }
 
 impl Problem {
@@ -19,13 +44,64 @@ impl Problem {
             ffi::papilo_problem_create(f64::INFINITY, problem_name.as_ptr(), 1000, 10, 10)
         };
         assert!(!raw.is_null());
-        Problem { raw }
+        Problem {
+            raw,
+            data: ProblemData::default(),
+        }
+    }
+
+    /// Builds a problem from a reduced problem, e.g. to presolve it again with
+    /// different settings or to modify it.
+    ///
+    /// Columns, rows and nonzeros keep their reduced indices. Columns are
+    /// named `x<index>` and rows `r<index>`. A `Problem` has no objective
+    /// offset, so [`ReducedProblem::objective_offset`] is not carried over.
+    ///
+    /// Returns an error if the fields of `reduced` are inconsistent with each
+    /// other, which can only happen if they were modified after extraction.
+    pub fn from_reduced(reduced: &ReducedProblem) -> Result<Self, MalformedProblem> {
+        let num_cols = reduced.num_cols;
+        let num_rows = reduced.num_rows;
+        check_len("col_lower", num_cols, reduced.col_lower.len())?;
+        check_len("col_upper", num_cols, reduced.col_upper.len())?;
+        check_len("col_integral", num_cols, reduced.col_integral.len())?;
+        check_len("objective", num_cols, reduced.objective.len())?;
+        check_len("row_lhs", num_rows, reduced.row_lhs.len())?;
+
+        let mut problem = Problem::new();
+        for col in 0..num_cols {
+            problem.add_col(
+                reduced.col_lower[col],
+                reduced.col_upper[col],
+                reduced.col_integral[col],
+                reduced.objective[col],
+                &format!("x{col}"),
+            );
+        }
+        problem.add_rows_csr(
+            &reduced.row_start,
+            &reduced.col_indices,
+            &reduced.values,
+            &reduced.row_lhs,
+            &reduced.row_rhs,
+        )?;
+        Ok(problem)
+    }
+
+    /// Gives access to the Rust-side copy of the problem data.
+    pub(crate) fn data(&self) -> &ProblemData {
+        &self.data
+    }
+
+    /// Moves the Rust-side copy of the problem data out, leaving it empty.
+    pub(crate) fn take_data(&mut self) -> ProblemData {
+        std::mem::take(&mut self.data)
     }
 
     /// Adds a column to the problem.
     pub fn add_col(&mut self, lb: f64, ub: f64, integer: bool, cost: f64, name: &str) -> usize {
         let c_name = CString::new(name).expect("Failed to create CString");
-        unsafe {
+        let col_id = unsafe {
             ffi::papilo_problem_add_col(
                 self.raw,
                 lb,
@@ -35,7 +111,15 @@ impl Problem {
                 c_name.as_ptr(),
             ).try_into()
                 .expect("Failed to add column")
-        }
+        };
+
+        self.data.col_lower.push(lb);
+        self.data.col_upper.push(ub);
+        self.data.col_integral.push(integer);
+        self.data.objective.push(cost);
+        self.data.col_names.push(name.to_owned());
+
+        col_id
     }
 
 
@@ -51,6 +135,10 @@ impl Problem {
             )
         } as usize;
 
+        self.data.row_lhs.push(lhs);
+        self.data.row_rhs.push(rhs);
+        self.data.row_names.push(name.to_owned());
+
         for &(col_id, coeff) in coefficients {
             self.set_row_coef(row_id, col_id, coeff);
         }
@@ -60,11 +148,73 @@ impl Problem {
     }
 
 
+    /// Adds one row per entry of `lhs` / `rhs`, with the nonzeros given in CSR
+    /// form: `row_start[r]..row_start[r + 1]` indexes into `col_indices` /
+    /// `values` for the `r`-th new row. Rows are named `r<index>`.
+    ///
+    /// Returns the indices of the new rows, or an error (before adding any
+    /// row) if the arrays are inconsistent or refer to a column that does not
+    /// exist.
+    pub fn add_rows_csr(
+        &mut self,
+        row_start: &[usize],
+        col_indices: &[usize],
+        values: &[f64],
+        lhs: &[f64],
+        rhs: &[f64],
+    ) -> Result<Range<usize>, MalformedProblem> {
+        let num_rows = lhs.len();
+        let num_cols = self.data.col_lower.len();
+        check_len("rhs", num_rows, rhs.len())?;
+        check_len("row_start", num_rows + 1, row_start.len())?;
+        check_len("values", col_indices.len(), values.len())?;
+        if row_start[0] != 0
+            || row_start.windows(2).any(|w| w[0] > w[1])
+            || row_start[num_rows] != col_indices.len()
+        {
+            return Err(MalformedProblem::InvalidRowStart);
+        }
+        for (row, bounds) in row_start.windows(2).enumerate() {
+            if let Some(&col) = col_indices[bounds[0]..bounds[1]]
+                .iter()
+                .find(|&&col| col >= num_cols)
+            {
+                return Err(MalformedProblem::ColumnOutOfRange { row, col });
+            }
+        }
+
+        let first = self.data.row_lhs.len();
+        for (row, bounds) in row_start.windows(2).enumerate() {
+            let coefficients: Vec<_> = (bounds[0]..bounds[1])
+                .map(|k| (col_indices[k], values[k]))
+                .collect();
+            self.add_row(
+                &format!("r{}", first + row),
+                &coefficients,
+                lhs[row],
+                rhs[row],
+            );
+        }
+        Ok(first..first + num_rows)
+    }
+
     /// Sets a coefficient for a specific row and column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_id` is not a row or `col_id` is not a column of the
+    /// problem.
     pub fn set_row_coef(&mut self, row_id: usize, col_id: usize, value: f64) {
+        assert!(
+            row_id < self.data.row_lhs.len(),
+            "row {row_id} out of range for a problem with {} rows",
+            self.data.row_lhs.len()
+        );
+        self.check_col(col_id);
         unsafe {
             ffi::papilo_problem_add_nonzero(self.raw, row_id as i32, col_id as i32, value);
         }
+        self.data.entries.push((row_id, col_id, value));
     }
 
     /// Returns the number of columns (variables) currently in the problem.
@@ -78,25 +228,133 @@ impl Problem {
     }
 
     /// Changes the lower bound of a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_id` is not a column of the problem.
     pub fn change_col_lb(&mut self, col_id: usize, lb: f64) {
+        self.check_col(col_id);
         unsafe { ffi::papilo_problem_change_col_lb(self.raw, col_id as i32, lb) };
+        self.data.col_lower[col_id] = lb;
     }
 
     /// Changes the upper bound of a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_id` is not a column of the problem.
     pub fn change_col_ub(&mut self, col_id: usize, ub: f64) {
+        self.check_col(col_id);
         unsafe { ffi::papilo_problem_change_col_ub(self.raw, col_id as i32, ub) };
+        self.data.col_upper[col_id] = ub;
     }
 
     /// Changes the objective coefficient of a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_id` is not a column of the problem.
     pub fn change_col_obj(&mut self, col_id: usize, obj: f64) {
+        self.check_col(col_id);
         unsafe { ffi::papilo_problem_change_col_obj(self.raw, col_id as i32, obj) };
+        self.data.objective[col_id] = obj;
     }
 
     /// Changes whether a column is integer-constrained.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_id` is not a column of the problem.
     pub fn change_col_integral(&mut self, col_id: usize, integral: bool) {
+        self.check_col(col_id);
         unsafe {
             ffi::papilo_problem_change_col_integral(self.raw, col_id as i32, integral.into())
         };
+        self.data.col_integral[col_id] = integral;
+    }
+
+    /// Panics if `col_id` is not a column of the problem.
+    fn check_col(&self, col_id: usize) {
+        assert!(
+            col_id < self.data.col_lower.len(),
+            "column {col_id} out of range for a problem with {} columns",
+            self.data.col_lower.len()
+        );
+    }
+}
+
+/// Returns an error if `field` has `got` entries instead of `expected`.
+fn check_len(field: &'static str, expected: usize, got: usize) -> Result<(), MalformedProblem> {
+    if expected == got {
+        Ok(())
+    } else {
+        Err(MalformedProblem::WrongLength {
+            field,
+            expected,
+            got,
+        })
+    }
+}
+
+impl ProblemData {
+    /// Builds an [`OriginalProblem`] snapshot of the data, with the matrix in
+    /// CSR form.
+    pub(crate) fn to_original(&self) -> OriginalProblem {
+        let (row_start, col_indices, values) = self.csr();
+        OriginalProblem {
+            num_cols: self.col_lower.len(),
+            num_rows: self.row_lhs.len(),
+            col_lower: self.col_lower.clone(),
+            col_upper: self.col_upper.clone(),
+            col_integral: self.col_integral.clone(),
+            objective: self.objective.clone(),
+            col_names: self.col_names.clone(),
+            row_lhs: self.row_lhs.clone(),
+            row_rhs: self.row_rhs.clone(),
+            row_names: self.row_names.clone(),
+            row_start,
+            col_indices,
+            values,
+        }
+    }
+
+    /// The matrix in CSR form: row pointers, column indices and values, with
+    /// increasing column indices within each row. Of several entries for the
+    /// same row and column, the last one set is used.
+    pub(crate) fn csr(&self) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+        // A stable sort keeps entries for the same position in the order they
+        // were set.
+        let mut order: Vec<usize> = (0..self.entries.len()).collect();
+        order.sort_by_key(|&k| (self.entries[k].0, self.entries[k].1));
+
+        let num_rows = self.row_lhs.len();
+        let mut row_start = vec![0; num_rows + 1];
+        let mut col_indices = Vec::with_capacity(order.len());
+        let mut values = Vec::with_capacity(order.len());
+        let mut last = None;
+        for k in order {
+            let (row, col, value) = self.entries[k];
+            if last == Some((row, col)) {
+                *values.last_mut().unwrap() = value;
+                continue;
+            }
+            last = Some((row, col));
+            row_start[row + 1] += 1;
+            col_indices.push(col);
+            values.push(value);
+        }
+        for row in 0..num_rows {
+            row_start[row + 1] += row_start[row];
+        }
+        (row_start, col_indices, values)
+    }
+}
+
+impl TryFrom<&ReducedProblem> for Problem {
+    type Error = MalformedProblem;
+
+    fn try_from(reduced: &ReducedProblem) -> Result<Self, Self::Error> {
+        Problem::from_reduced(reduced)
     }
 }
 
@@ -113,3 +371,75 @@ impl Drop for Problem {
         }
     }
 }
+
+/// Error returned when problem data passed to [`Problem::from_reduced`] or
+/// [`Problem::add_rows_csr`] is inconsistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MalformedProblem {
+    /// A vector does not have the length implied by the dimensions.
+    WrongLength {
+        /// Name of the offending field.
+        field: &'static str,
+        /// The expected number of entries.
+        expected: usize,
+        /// The actual number of entries.
+        got: usize,
+    },
+    /// The CSR row pointers do not start at zero, decrease, or do not end at
+    /// the number of nonzeros.
+    InvalidRowStart,
+    /// A nonzero refers to a column that does not exist.
+    ColumnOutOfRange {
+        /// The row containing the nonzero, counted from the first row passed.
+        row: usize,
+        /// The out-of-range column index.
+        col: usize,
+    },
+}
+
+impl std::fmt::Display for MalformedProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MalformedProblem::WrongLength {
+                field,
+                expected,
+                got,
+            } => write!(
+                f,
+                "`{field}` has wrong length: expected {expected}, got {got}"
+            ),
+            MalformedProblem::InvalidRowStart => write!(f, "invalid CSR row pointers"),
+            MalformedProblem::ColumnOutOfRange { row, col } => {
+                write!(f, "row {row} refers to column {col}, which does not exist")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MalformedProblem {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csr_sorts_entries_and_keeps_last_value() {
+        let data = ProblemData {
+            col_lower: vec![0.0; 3],
+            row_lhs: vec![0.0; 3],
+            entries: vec![
+                (2, 1, 1.0),
+                (0, 2, 2.0),
+                (0, 0, 3.0),
+                (2, 1, 4.0),
+                (0, 2, 5.0),
+            ],
+            ..ProblemData::default()
+        };
+
+        let (row_start, col_indices, values) = data.csr();
+        assert_eq!(row_start, vec![0, 2, 2, 3]);
+        assert_eq!(col_indices, vec![0, 2, 1]);
+        assert_eq!(values, vec![3.0, 5.0, 4.0]);
+    }
+}
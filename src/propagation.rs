@@ -0,0 +1,397 @@
+use crate::model::ModelRef;
+use crate::numerics::NumericsThresholds;
+use crate::presolve::Tolerances;
+use crate::problem::Problem;
+
+/// Settings for [`Problem::propagate_bounds`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropagationSettings {
+    /// Integer bounds are rounded using `integrality`; a bound must improve
+    /// by more than `feasibility`, relative to its magnitude, to count as a
+    /// change.
+    pub tolerances: Tolerances,
+    /// Derived bounds with an absolute value at or above this are discarded.
+    /// Default `1e8`, as [`NumericsThresholds::huge`] (`numerics.hugeval`).
+    pub huge: f64,
+    /// Maximum number of passes over all rows. Default `100`.
+    pub max_rounds: usize,
+}
+
+impl Default for PropagationSettings {
+    fn default() -> Self {
+        PropagationSettings {
+            tolerances: Tolerances::default(),
+            huge: NumericsThresholds::default().huge,
+            max_rounds: 100,
+        }
+    }
+}
+
+/// Column bounds tightened by [`Problem::propagate_bounds`], indexed like the
+/// columns of the problem.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropagatedBounds {
+    /// Lower bound of each column (`f64::NEG_INFINITY` if unbounded below).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub col_lower: Vec<f64>,
+    /// Upper bound of each column (`f64::INFINITY` if unbounded above).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::vec"))]
+    pub col_upper: Vec<f64>,
+    /// Columns with at least one bound tighter than in the problem.
+    pub tightened: Vec<usize>,
+    /// Number of passes over the rows.
+    pub rounds: usize,
+    /// Whether the last pass changed no bound. If `false`, propagation
+    /// stopped at [`max_rounds`](PropagationSettings::max_rounds) and further
+    /// passes might tighten the bounds more.
+    pub converged: bool,
+}
+
+/// Error returned when [`Problem::propagate_bounds`] proves a problem
+/// infeasible.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Infeasibility {
+    /// The bounds of this column became empty.
+    Col(usize),
+    /// The activity of this row cannot reach one of its sides.
+    Row(usize),
+}
+
+impl std::fmt::Display for Infeasibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Infeasibility::Col(col) => {
+                write!(f, "problem is infeasible: column {col} has empty bounds")
+            }
+            Infeasibility::Row(row) => {
+                write!(f, "problem is infeasible: row {row} cannot be satisfied")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Infeasibility {}
+
+impl Problem {
+    /// Tightens the column bounds of the problem built so far by domain
+    /// propagation only, without removing or changing any row or column.
+    ///
+    /// This runs in Rust on the problem data rather than in PaPILO. Each
+    /// row's minimum and maximum activity bounds every column in it; passes
+    /// over all rows repeat until no bound changes or
+    /// [`max_rounds`](PropagationSettings::max_rounds) is reached. The problem
+    /// itself is not modified.
+    ///
+    /// Returns an error if propagation shows the problem is infeasible.
+    pub fn propagate_bounds(
+        &self,
+        settings: &PropagationSettings,
+    ) -> Result<PropagatedBounds, Infeasibility> {
+        let data = self.data();
+        let (row_start, col_indices, values) = data.csr();
+        propagate(data.model(&row_start, &col_indices, &values), settings)
+    }
+}
+
+/// Minimum and maximum activity of a row, split into a finite part and the
+/// number of infinite contributions.
+#[derive(Default)]
+struct Activity {
+    min: f64,
+    min_inf: usize,
+    max: f64,
+    max_inf: usize,
+}
+
+/// Tightens the column bounds of `model` using the minimum and maximum
+/// activities of its rows, see [`Problem::propagate_bounds`].
+fn propagate(
+    model: ModelRef<'_>,
+    settings: &PropagationSettings,
+) -> Result<PropagatedBounds, Infeasibility> {
+    let tolerances = &settings.tolerances;
+    let mut lower = model.col_lower.to_vec();
+    let mut upper = model.col_upper.to_vec();
+    for col in 0..model.num_cols() {
+        if model.col_integral[col] {
+            lower[col] = (lower[col] - tolerances.integrality).ceil();
+            upper[col] = (upper[col] + tolerances.integrality).floor();
+        }
+        if lower[col] > upper[col] + tolerances.feasibility {
+            return Err(Infeasibility::Col(col));
+        }
+    }
+
+    let mut rounds = 0;
+    let mut changed = true;
+    while changed && rounds < settings.max_rounds {
+        changed = false;
+        rounds += 1;
+        for row in 0..model.num_rows() {
+            let (cols, values) = model.row(row);
+            let lhs = model.row_lhs[row];
+            let rhs = model.row_rhs[row];
+
+            // Contributions `a * l` and `a * u` are taken from the bounds at
+            // the start of the row, so that the residual activities below
+            // remain consistent while bounds in the row change. Explicit
+            // zeros are skipped, as `0 * inf` would count as infinite.
+            let contributions: Vec<(usize, f64, f64, f64)> = cols
+                .iter()
+                .zip(values)
+                .filter(|&(_, &a)| a != 0.0)
+                .map(|(&col, &a)| {
+                    if a > 0.0 {
+                        (col, a, a * lower[col], a * upper[col])
+                    } else {
+                        (col, a, a * upper[col], a * lower[col])
+                    }
+                })
+                .collect();
+            let mut activity = Activity::default();
+            for &(_, _, min, max) in &contributions {
+                if min.is_finite() {
+                    activity.min += min;
+                } else {
+                    activity.min_inf += 1;
+                }
+                if max.is_finite() {
+                    activity.max += max;
+                } else {
+                    activity.max_inf += 1;
+                }
+            }
+
+            if (activity.min_inf == 0 && activity.min > rhs + tolerances.feasibility)
+                || (activity.max_inf == 0 && activity.max < lhs - tolerances.feasibility)
+            {
+                return Err(Infeasibility::Row(row));
+            }
+
+            for &(col, a, min, max) in &contributions {
+                // Activity of the other columns, if finite.
+                let residual_min = match (activity.min_inf, min.is_finite()) {
+                    (0, _) => Some(activity.min - min),
+                    (1, false) => Some(activity.min),
+                    _ => None,
+                };
+                let residual_max = match (activity.max_inf, max.is_finite()) {
+                    (0, _) => Some(activity.max - max),
+                    (1, false) => Some(activity.max),
+                    _ => None,
+                };
+
+                // a x <= rhs - residual_min and a x >= lhs - residual_max.
+                let mut new_lower = f64::NEG_INFINITY;
+                let mut new_upper = f64::INFINITY;
+                if let Some(residual) = residual_min.filter(|_| rhs.is_finite()) {
+                    let bound = (rhs - residual) / a;
+                    if a > 0.0 {
+                        new_upper = bound;
+                    } else {
+                        new_lower = bound;
+                    }
+                }
+                if let Some(residual) = residual_max.filter(|_| lhs.is_finite()) {
+                    let bound = (lhs - residual) / a;
+                    if a > 0.0 {
+                        new_lower = new_lower.max(bound);
+                    } else {
+                        new_upper = new_upper.min(bound);
+                    }
+                }
+
+                if model.col_integral[col] {
+                    new_lower = (new_lower - tolerances.integrality).ceil();
+                    new_upper = (new_upper + tolerances.integrality).floor();
+                }
+                if new_lower.abs() < settings.huge
+                    && lower_improves(lower[col], new_lower, tolerances)
+                {
+                    lower[col] = new_lower;
+                    changed = true;
+                }
+                if new_upper.abs() < settings.huge
+                    && upper_improves(upper[col], new_upper, tolerances)
+                {
+                    upper[col] = new_upper;
+                    changed = true;
+                }
+
+                if lower[col] > upper[col] + tolerances.feasibility {
+                    return Err(Infeasibility::Col(col));
+                }
+                if lower[col] > upper[col] {
+                    // Crossed within tolerance; fix the column, without
+                    // leaving the bounds it had in the problem.
+                    let value = if model.col_integral[col] {
+                        lower[col].round()
+                    } else {
+                        0.5 * (lower[col] + upper[col])
+                    };
+                    let value = value.max(model.col_lower[col]).min(model.col_upper[col]);
+                    lower[col] = value;
+                    upper[col] = value;
+                }
+            }
+        }
+    }
+
+    let tightened = (0..model.num_cols())
+        .filter(|&col| lower[col] > model.col_lower[col] || upper[col] < model.col_upper[col])
+        .collect();
+    Ok(PropagatedBounds {
+        col_lower: lower,
+        col_upper: upper,
+        tightened,
+        rounds,
+        converged: !changed,
+    })
+}
+
+/// Whether raising the lower bound `old` to `new` is a significant change,
+/// relative to the magnitude of `old`.
+fn lower_improves(old: f64, new: f64, tolerances: &Tolerances) -> bool {
+    if old.is_infinite() {
+        return new > old;
+    }
+    new - old > tolerances.feasibility * old.abs().max(1.0)
+}
+
+/// Whether lowering the upper bound `old` to `new` is a significant change,
+/// relative to the magnitude of `old`.
+fn upper_improves(old: f64, new: f64, tolerances: &Tolerances) -> bool {
+    if old.is_infinite() {
+        return new < old;
+    }
+    old - new > tolerances.feasibility * old.abs().max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::original_problem as problem;
+
+    const INF: f64 = f64::INFINITY;
+
+    #[test]
+    fn tightens_bounds_from_row_activities() {
+        // x + 2y <= 6 ; x - y >= 1 ; x, y >= 0, y integer.
+        let problem = problem(
+            &[(0.0, INF, false), (0.0, INF, true)],
+            &[
+                (&[(0, 1.0), (1, 2.0)], -INF, 6.0),
+                (&[(0, 1.0), (1, -1.0)], 1.0, INF),
+            ],
+        );
+        let bounds = propagate(problem.model(), &PropagationSettings::default()).unwrap();
+
+        assert_eq!(bounds.col_lower, vec![1.0, 0.0]);
+        assert_eq!(bounds.col_upper, vec![6.0, 2.0]);
+        assert_eq!(bounds.tightened, vec![0, 1]);
+        assert!(bounds.rounds >= 2);
+        assert!(bounds.converged);
+    }
+
+    #[test]
+    fn propagates_through_chains_until_fixpoint() {
+        // x0 = 1 ; x1 <= x0 ; x2 <= x1, all in [0, 10].
+        let chain = problem(
+            &[(1.0, 1.0, false), (0.0, 10.0, false), (0.0, 10.0, false)],
+            &[
+                (&[(1, 1.0), (0, -1.0)], -INF, 0.0),
+                (&[(2, 1.0), (1, -1.0)], -INF, 0.0),
+            ],
+        );
+        let bounds = propagate(chain.model(), &PropagationSettings::default()).unwrap();
+
+        assert_eq!(bounds.col_upper, vec![1.0, 1.0, 1.0]);
+        assert_eq!(bounds.tightened, vec![1, 2]);
+
+        // With the rows reversed, a single pass only reaches x1.
+        let reversed = problem(
+            &[(1.0, 1.0, false), (0.0, 10.0, false), (0.0, 10.0, false)],
+            &[
+                (&[(2, 1.0), (1, -1.0)], -INF, 0.0),
+                (&[(1, 1.0), (0, -1.0)], -INF, 0.0),
+            ],
+        );
+        let settings = PropagationSettings {
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let bounds = propagate(reversed.model(), &settings).unwrap();
+
+        assert_eq!(bounds.col_upper, vec![1.0, 1.0, 10.0]);
+        assert_eq!(bounds.rounds, 1);
+        assert!(!bounds.converged);
+    }
+
+    #[test]
+    fn detects_infeasibility() {
+        // x + y >= 3 with x, y in [0, 1].
+        let sum = problem(
+            &[(0.0, 1.0, false), (0.0, 1.0, false)],
+            &[(&[(0, 1.0), (1, 1.0)], 3.0, INF)],
+        );
+        assert_eq!(
+            propagate(sum.model(), &PropagationSettings::default()),
+            Err(Infeasibility::Row(0))
+        );
+
+        // 2x = 1 with x integer.
+        let odd = problem(&[(0.0, 1.0, true)], &[(&[(0, 2.0)], 1.0, 1.0)]);
+        assert_eq!(
+            propagate(odd.model(), &PropagationSettings::default()),
+            Err(Infeasibility::Col(0))
+        );
+    }
+
+    #[test]
+    fn explicit_zeros_do_not_block_tightening() {
+        // 0 x0 + x1 <= 0.5 with x0 free and x1 in [0, 1].
+        let problem = problem(
+            &[(-INF, INF, false), (0.0, 1.0, false)],
+            &[(&[(0, 0.0), (1, 1.0)], -INF, 0.5)],
+        );
+        let bounds = propagate(problem.model(), &PropagationSettings::default()).unwrap();
+
+        assert_eq!(bounds.col_lower, vec![-INF, 0.0]);
+        assert_eq!(bounds.col_upper, vec![INF, 0.5]);
+        assert_eq!(bounds.tightened, vec![1]);
+    }
+
+    #[test]
+    fn crossed_bounds_stay_within_problem_bounds() {
+        // x >= 1.0000005 with x in [0, 1] crosses the upper bound within the
+        // feasibility tolerance.
+        let problem = problem(&[(0.0, 1.0, false)], &[(&[(0, 1.0)], 1.0000005, INF)]);
+        let bounds = propagate(problem.model(), &PropagationSettings::default()).unwrap();
+
+        assert_eq!(bounds.col_lower, vec![1.0]);
+        assert_eq!(bounds.col_upper, vec![1.0]);
+    }
+
+    #[test]
+    fn ignores_huge_derived_bounds() {
+        // x - 1e-9 y <= 0 with y <= 1e20 would give x <= 1e11.
+        let problem = problem(
+            &[(0.0, INF, false), (0.0, 1e20, false)],
+            &[(&[(0, 1.0), (1, -1e-9)], -INF, 0.0)],
+        );
+        let bounds = propagate(problem.model(), &PropagationSettings::default()).unwrap();
+
+        assert_eq!(bounds.col_upper, vec![INF, 1e20]);
+        assert!(bounds.tightened.is_empty());
+
+        let settings = PropagationSettings {
+            huge: 1e12,
+            ..Default::default()
+        };
+        let bounds = propagate(problem.model(), &settings).unwrap();
+        assert_eq!(bounds.tightened, vec![0]);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::presolve::PresolveStatus;
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+/// Summary of a presolve run, as returned by
+/// [`Presolver::report`](crate::presolver::Presolver::report).
+///
+/// The [`Display`](fmt::Display) implementation renders it as a plain text
+/// table; [`to_markdown`](Self::to_markdown) and [`to_json`](Self::to_json)
+/// render the same content as a Markdown table and a JSON object. The JSON
+/// object is the same as the one produced by the `serde` feature's
+/// `Serialize` implementation.
+///
+/// PaPILO's C interface does not expose per-presolver statistics, so the
+/// report only covers the overall effect of presolving.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresolveReport {
+    /// The status returned by presolving.
+    pub status: PresolveStatus,
+    /// Size of the original problem.
+    pub original: ProblemSize,
+    /// Size of the reduced problem.
+    pub reduced: ProblemSize,
+    /// Constant objective offset of the reduced problem.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::scalar"))]
+    pub objective_offset: f64,
+    /// Number of [`restart`](crate::presolver::Presolver::restart)s.
+    pub restarts: usize,
+    /// Wall-clock time spent in PaPILO's presolve routine, summed over the
+    /// initial presolve and all restarts. Serialized in seconds as
+    /// `time_secs`.
+    #[cfg_attr(feature = "serde", serde(rename = "time_secs", with = "time_secs"))]
+    pub time: Duration,
+}
+
+/// Dimensions of a problem.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProblemSize {
+    /// Number of rows.
+    pub rows: usize,
+    /// Number of columns.
+    pub cols: usize,
+    /// Number of nonzeros in the constraint matrix.
+    pub nonzeros: usize,
+    /// Number of integer columns.
+    pub integers: usize,
+}
+
+impl PresolveReport {
+    /// The report as a Markdown table, preceded by status, restarts, time and
+    /// objective offset.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "**Status:** {:?}  ", self.status).unwrap();
+        writeln!(out, "**Restarts:** {}  ", self.restarts).unwrap();
+        writeln!(out, "**Time:** {:.3} s  ", self.time.as_secs_f64()).unwrap();
+        writeln!(out, "**Objective offset:** {}", self.objective_offset).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "| | Original | Reduced | Removed |").unwrap();
+        writeln!(out, "|---|---:|---:|---:|").unwrap();
+        for (name, original, reduced) in self.rows() {
+            writeln!(
+                out,
+                "| {name} | {original} | {reduced} | {} |",
+                removed(original, reduced)
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// The report as a JSON object. Sizes are nested under `"original"` and
+    /// `"reduced"`, the time is given in seconds as `"time_secs"` and a
+    /// non-finite objective offset is written as `"inf"`, `"-inf"` or
+    /// `"nan"`.
+    pub fn to_json(&self) -> String {
+        fn size(size: &ProblemSize) -> String {
+            format!(
+                r#"{{"rows":{},"cols":{},"nonzeros":{},"integers":{}}}"#,
+                size.rows, size.cols, size.nonzeros, size.integers
+            )
+        }
+        let offset = match self.objective_offset {
+            value if value.is_finite() => format!("{value:?}"),
+            value if value.is_nan() => r#""nan""#.to_owned(),
+            value if value > 0.0 => r#""inf""#.to_owned(),
+            _ => r#""-inf""#.to_owned(),
+        };
+        format!(
+            r#"{{"status":"{:?}","original":{},"reduced":{},"objective_offset":{},"restarts":{},"time_secs":{:?}}}"#,
+            self.status,
+            size(&self.original),
+            size(&self.reduced),
+            offset,
+            self.restarts,
+            self.time.as_secs_f64()
+        )
+    }
+
+    /// The table rows: name, original and reduced count.
+    fn rows(&self) -> [(&'static str, usize, usize); 4] {
+        [
+            ("rows", self.original.rows, self.reduced.rows),
+            ("columns", self.original.cols, self.reduced.cols),
+            ("nonzeros", self.original.nonzeros, self.reduced.nonzeros),
+            ("integers", self.original.integers, self.reduced.integers),
+        ]
+    }
+}
+
+/// How many of `original` were removed, with the percentage.
+fn removed(original: usize, reduced: usize) -> String {
+    let removed = original.saturating_sub(reduced);
+    if original == 0 {
+        return removed.to_string();
+    }
+    format!(
+        "{removed} ({:.1}%)",
+        100.0 * removed as f64 / original as f64
+    )
+}
+
+impl fmt::Display for PresolveReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "status {:?}, {} restart(s), {:.3} s, objective offset {}",
+            self.status,
+            self.restarts,
+            self.time.as_secs_f64(),
+            self.objective_offset
+        )?;
+        write!(
+            f,
+            "{:<10}{:>12}{:>12}{:>18}",
+            "", "original", "reduced", "removed"
+        )?;
+        for (name, original, reduced) in self.rows() {
+            write!(
+                f,
+                "\n{name:<10}{original:>12}{reduced:>12}{:>18}",
+                removed(original, reduced)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// `#[serde(with = "time_secs")]` for a [`Duration`] written as seconds.
+#[cfg(feature = "serde")]
+mod time_secs {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+    use std::time::Duration;
+
+    pub(super) fn serialize<S: Serializer>(
+        time: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(time.as_secs_f64())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(secs).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> PresolveReport {
+        PresolveReport {
+            status: PresolveStatus::Reduced,
+            original: ProblemSize {
+                rows: 4,
+                cols: 3,
+                nonzeros: 8,
+                integers: 2,
+            },
+            reduced: ProblemSize {
+                rows: 1,
+                cols: 2,
+                nonzeros: 2,
+                integers: 2,
+            },
+            objective_offset: 1.5,
+            restarts: 0,
+            time: Duration::from_millis(12),
+        }
+    }
+
+    #[test]
+    fn renders_text_table() {
+        let text = report().to_string();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "status Reduced, 0 restart(s), 0.012 s, objective offset 1.5"
+        );
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[2].split_whitespace().collect::<Vec<_>>(),
+            ["rows", "4", "1", "3", "(75.0%)"]
+        );
+        assert_eq!(
+            lines[5].split_whitespace().collect::<Vec<_>>(),
+            ["integers", "2", "2", "0", "(0.0%)"]
+        );
+    }
+
+    #[test]
+    fn renders_markdown_table() {
+        let markdown = report().to_markdown();
+
+        assert!(markdown.starts_with("**Status:** Reduced  \n"));
+        assert!(markdown.contains("| | Original | Reduced | Removed |\n|---|---:|---:|---:|\n"));
+        assert!(markdown.contains("| columns | 3 | 2 | 1 (33.3%) |\n"));
+    }
+
+    #[test]
+    fn renders_json_object() {
+        assert_eq!(
+            report().to_json(),
+            r#"{"status":"Reduced","original":{"rows":4,"cols":3,"nonzeros":8,"integers":2},"reduced":{"rows":1,"cols":2,"nonzeros":2,"integers":2},"objective_offset":1.5,"restarts":0,"time_secs":0.012}"#
+        );
+
+        let mut report = report();
+        report.objective_offset = f64::NEG_INFINITY;
+        assert!(report.to_json().contains(r#""objective_offset":"-inf""#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_matches_serde() {
+        let mut report = report();
+        report.objective_offset = 30.0;
+        assert_eq!(report.to_json(), serde_json::to_string(&report).unwrap());
+        let back: PresolveReport = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(back, report);
+
+        report.objective_offset = f64::INFINITY;
+        assert_eq!(report.to_json(), serde_json::to_string(&report).unwrap());
+    }
+}
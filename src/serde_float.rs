@@ -0,0 +1,171 @@
+//! Serde helpers that encode non-finite `f64` values portably.
+//!
+//! Human-readable formats such as JSON cannot represent infinities, so they
+//! are written as the strings `"inf"`, `"-inf"` and `"nan"`. Binary formats
+//! keep the plain `f64`.
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// An `f64` with the portable encoding.
+struct Portable(f64);
+
+impl Serialize for Portable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.0;
+        if !serializer.is_human_readable() || value.is_finite() {
+            serializer.serialize_f64(value)
+        } else if value.is_nan() {
+            serializer.serialize_str("nan")
+        } else if value > 0.0 {
+            serializer.serialize_str("inf")
+        } else {
+            serializer.serialize_str("-inf")
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Portable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PortableVisitor)
+        } else {
+            f64::deserialize(deserializer).map(Portable)
+        }
+    }
+}
+
+struct PortableVisitor;
+
+impl Visitor<'_> for PortableVisitor {
+    type Value = Portable;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(r#"a number or one of "inf", "-inf", "nan""#)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Portable, E> {
+        Ok(Portable(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Portable, E> {
+        Ok(Portable(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Portable, E> {
+        Ok(Portable(value as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Portable, E> {
+        match value {
+            "inf" => Ok(Portable(f64::INFINITY)),
+            "-inf" => Ok(Portable(f64::NEG_INFINITY)),
+            "nan" => Ok(Portable(f64::NAN)),
+            _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+/// `#[serde(with = "crate::serde_float::scalar")]` for `f64` fields.
+pub(crate) mod scalar {
+    use super::Portable;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        Portable(*value).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        Portable::deserialize(deserializer).map(|v| v.0)
+    }
+}
+
+/// `#[serde(with = "crate::serde_float::vec")]` for `Vec<f64>` fields.
+pub(crate) mod vec {
+    use super::Portable;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        values: &[f64],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|&v| Portable(v)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<f64>, D::Error> {
+        let values = Vec::<Portable>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|v| v.0).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presolve::{FeasibilityReport, PostsolveError, PresolveStatus, ReducedProblem};
+
+    fn reduced() -> ReducedProblem {
+        ReducedProblem {
+            num_cols: 2,
+            num_rows: 1,
+            col_lower: vec![0.0, f64::NEG_INFINITY],
+            col_upper: vec![f64::INFINITY, 10.0],
+            col_integral: vec![true, false],
+            objective: vec![1.0, -2.5],
+            objective_offset: 3.0,
+            row_lhs: vec![f64::NEG_INFINITY],
+            row_rhs: vec![4.0],
+            row_start: vec![0, 2],
+            col_indices: vec![0, 1],
+            values: vec![1.0, 2.0],
+            orig_col: vec![0, 2],
+            orig_row: vec![1],
+        }
+    }
+
+    #[test]
+    fn json_encodes_infinities_as_strings() {
+        let reduced = reduced();
+        let json = serde_json::to_string(&reduced).unwrap();
+        assert!(json.contains(r#""col_lower":[0.0,"-inf"]"#), "{json}");
+        assert!(json.contains(r#""col_upper":["inf",10.0]"#), "{json}");
+
+        let back: ReducedProblem = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, reduced);
+    }
+
+    #[test]
+    fn json_encodes_scalar_infinities_as_strings() {
+        let report = FeasibilityReport {
+            objective: f64::NEG_INFINITY,
+            max_bound_violation: 0.5,
+            max_row_violation: f64::INFINITY,
+            violated_rows: vec![0],
+            ..FeasibilityReport::default()
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains(r#""objective":"-inf""#), "{json}");
+        assert!(json.contains(r#""max_row_violation":"inf""#), "{json}");
+        assert!(json.contains(r#""max_bound_violation":0.5"#), "{json}");
+
+        let back: FeasibilityReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+
+    #[test]
+    fn json_round_trips_statuses_and_errors() {
+        let json = serde_json::to_string(&PresolveStatus::Infeasible).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PresolveStatus>(&json).unwrap(),
+            PresolveStatus::Infeasible
+        );
+
+        let err = PostsolveError::WrongLength {
+            expected: 3,
+            got: 2,
+        };
+        let json = serde_json::to_string(&err).unwrap();
+        assert_eq!(serde_json::from_str::<PostsolveError>(&json).unwrap(), err);
+    }
+}
@@ -0,0 +1,253 @@
+//! Conversions between the constraint matrices of this crate and the sparse
+//! matrix types of [`sprs`], [`nalgebra_sparse`] and [`faer`], each behind the
+//! cargo feature of the same name.
+
+use crate::presolve::ReducedProblem;
+use crate::problem::{MalformedProblem, Problem};
+#[cfg(feature = "sprs")]
+use std::borrow::Cow;
+use std::ops::Range;
+
+#[cfg(feature = "sprs")]
+impl ReducedProblem {
+    /// Returns the constraint matrix as a CSR [`sprs::CsMat`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CSR fields are inconsistent, which can only happen if they
+    /// were modified after extraction.
+    pub fn to_sprs(&self) -> sprs::CsMat<f64> {
+        sprs::CsMat::new_from_unsorted(
+            (self.num_rows, self.num_cols),
+            self.row_start.clone(),
+            self.col_indices.clone(),
+            self.values.clone(),
+        )
+        .map_err(|(_, _, _, err)| err)
+        .expect("reduced problem has a valid CSR matrix")
+    }
+}
+
+#[cfg(feature = "sprs")]
+impl Problem {
+    /// Adds one row per row of `matrix`, which may be stored as CSR or CSC,
+    /// with the given sides. See [`add_rows_csr`](Self::add_rows_csr).
+    pub fn add_rows_sprs(
+        &mut self,
+        matrix: &sprs::CsMat<f64>,
+        lhs: &[f64],
+        rhs: &[f64],
+    ) -> Result<Range<usize>, MalformedProblem> {
+        check_num_cols(self, matrix.cols())?;
+        let csr = if matrix.is_csr() {
+            Cow::Borrowed(matrix)
+        } else {
+            Cow::Owned(matrix.to_csr())
+        };
+        let indptr = csr.indptr();
+        let row_start = indptr.to_proper();
+        self.add_rows_csr(&row_start, csr.indices(), csr.data(), lhs, rhs)
+    }
+}
+
+#[cfg(feature = "nalgebra-sparse")]
+impl ReducedProblem {
+    /// Returns the constraint matrix as a [`nalgebra_sparse::CsrMatrix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CSR fields are inconsistent, which can only happen if they
+    /// were modified after extraction.
+    pub fn to_nalgebra_csr(&self) -> nalgebra_sparse::CsrMatrix<f64> {
+        nalgebra_sparse::CsrMatrix::try_from_unsorted_csr_data(
+            self.num_rows,
+            self.num_cols,
+            self.row_start.clone(),
+            self.col_indices.clone(),
+            self.values.clone(),
+        )
+        .expect("reduced problem has a valid CSR matrix")
+    }
+}
+
+#[cfg(feature = "nalgebra-sparse")]
+impl Problem {
+    /// Adds one row per row of `matrix` with the given sides. See
+    /// [`add_rows_csr`](Self::add_rows_csr).
+    pub fn add_rows_nalgebra(
+        &mut self,
+        matrix: &nalgebra_sparse::CsrMatrix<f64>,
+        lhs: &[f64],
+        rhs: &[f64],
+    ) -> Result<Range<usize>, MalformedProblem> {
+        check_num_cols(self, matrix.ncols())?;
+        self.add_rows_csr(
+            matrix.row_offsets(),
+            matrix.col_indices(),
+            matrix.values(),
+            lhs,
+            rhs,
+        )
+    }
+}
+
+#[cfg(feature = "faer")]
+impl ReducedProblem {
+    /// Returns the constraint matrix as a [`faer::sparse::SparseRowMat`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CSR fields are inconsistent, which can only happen if they
+    /// were modified after extraction.
+    pub fn to_faer(&self) -> faer::sparse::SparseRowMat<usize, f64> {
+        let symbolic = faer::sparse::SymbolicSparseRowMat::new_unsorted_checked(
+            self.num_rows,
+            self.num_cols,
+            self.row_start.clone(),
+            None,
+            self.col_indices.clone(),
+        );
+        faer::sparse::SparseRowMat::new(symbolic, self.values.clone())
+    }
+}
+
+#[cfg(feature = "faer")]
+impl Problem {
+    /// Adds one row per row of `matrix` with the given sides. See
+    /// [`add_rows_csr`](Self::add_rows_csr).
+    pub fn add_rows_faer(
+        &mut self,
+        matrix: faer::sparse::SparseRowMatRef<'_, usize, f64>,
+        lhs: &[f64],
+        rhs: &[f64],
+    ) -> Result<Range<usize>, MalformedProblem> {
+        check_num_cols(self, matrix.ncols())?;
+        let symbolic = matrix.symbolic();
+        if symbolic.row_nnz().is_none() {
+            return self.add_rows_csr(
+                symbolic.row_ptr(),
+                symbolic.col_idx(),
+                matrix.val(),
+                lhs,
+                rhs,
+            );
+        }
+
+        // Rows are not stored contiguously; compact them first.
+        let mut row_start = vec![0];
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        for row in 0..matrix.nrows() {
+            col_indices.extend(symbolic.col_idx_of_row(row));
+            values.extend_from_slice(matrix.val_of_row(row));
+            row_start.push(col_indices.len());
+        }
+        self.add_rows_csr(&row_start, &col_indices, &values, lhs, rhs)
+    }
+}
+
+/// Returns an error if a matrix with `num_cols` columns does not match the
+/// columns of `problem`.
+fn check_num_cols(problem: &Problem, num_cols: usize) -> Result<(), MalformedProblem> {
+    let expected = problem.num_cols();
+    if num_cols == expected {
+        Ok(())
+    } else {
+        Err(MalformedProblem::WrongLength {
+            field: "matrix columns",
+            expected,
+            got: num_cols,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presolve::ReducedProblem;
+    use crate::presolver::Presolver;
+    use crate::problem::Problem;
+
+    /// Presolves `min x + y ; x + 2y >= 4 ; 3x + y >= 5` without changing it.
+    fn reduced() -> ReducedProblem {
+        let mut problem = Problem::new();
+        let x = problem.add_col(0.0, 10.0, false, 1.0, "x");
+        let y = problem.add_col(0.0, 10.0, false, 1.0, "y");
+        problem.add_row("r0", &[(x, 1.0), (y, 2.0)], 4.0, f64::INFINITY);
+        problem.add_row("r1", &[(x, 3.0), (y, 1.0)], 5.0, f64::INFINITY);
+
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        presolver.presolve(problem).reduced_problem()
+    }
+
+    /// A problem with the columns of `reduced` and no rows.
+    fn columns_of(reduced: &ReducedProblem) -> Problem {
+        let mut problem = Problem::new();
+        for col in 0..reduced.num_cols {
+            problem.add_col(
+                reduced.col_lower[col],
+                reduced.col_upper[col],
+                reduced.col_integral[col],
+                reduced.objective[col],
+                &format!("x{col}"),
+            );
+        }
+        problem
+    }
+
+    /// Presolves `problem` without changes and checks its matrix against
+    /// `reduced`.
+    fn assert_same_matrix(problem: Problem, reduced: &ReducedProblem) {
+        let mut presolver = Presolver::new();
+        presolver.set_param("presolve.dualreds", 0).unwrap();
+        let original = presolver.presolve(problem).original_problem();
+        assert_eq!(original.row_start, reduced.row_start);
+        assert_eq!(original.col_indices, reduced.col_indices);
+        assert_eq!(original.values, reduced.values);
+    }
+
+    #[cfg(feature = "sprs")]
+    #[test]
+    fn sprs_round_trip() {
+        let reduced = reduced();
+        let matrix = reduced.to_sprs();
+        assert_eq!(matrix.shape(), (2, 2));
+        assert_eq!(matrix.get(1, 0), Some(&3.0));
+
+        // CSC input is converted to CSR.
+        let mut problem = columns_of(&reduced);
+        let rows = problem
+            .add_rows_sprs(&matrix.to_csc(), &reduced.row_lhs, &reduced.row_rhs)
+            .unwrap();
+        assert_eq!(rows, 0..2);
+        assert_same_matrix(problem, &reduced);
+    }
+
+    #[cfg(feature = "nalgebra-sparse")]
+    #[test]
+    fn nalgebra_round_trip() {
+        let reduced = reduced();
+        let matrix = reduced.to_nalgebra_csr();
+        assert_eq!(matrix.nnz(), 4);
+
+        let mut problem = columns_of(&reduced);
+        problem
+            .add_rows_nalgebra(&matrix, &reduced.row_lhs, &reduced.row_rhs)
+            .unwrap();
+        assert_same_matrix(problem, &reduced);
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn faer_round_trip() {
+        let reduced = reduced();
+        let matrix = reduced.to_faer();
+        assert_eq!(matrix.compute_nnz(), 4);
+
+        let mut problem = columns_of(&reduced);
+        problem
+            .add_rows_faer(matrix.as_ref(), &reduced.row_lhs, &reduced.row_rhs)
+            .unwrap();
+        assert_same_matrix(problem, &reduced);
+    }
+}
@@ -0,0 +1,590 @@
+use crate::model::ModelRef;
+use crate::presolve::{OriginalProblem, ReducedProblem};
+use crate::problem::Problem;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Tolerance used to decide whether a coefficient or side is integral.
+const INTEGRALITY_EPS: f64 = 1e-9;
+
+/// Size, variable types, bound and row kinds, value ranges and constraint
+/// classification of a problem, as returned by
+/// [`ReducedProblem::statistics`], [`OriginalProblem::statistics`] and
+/// [`Problem::statistics`].
+///
+/// The [`Display`](fmt::Display) implementation prints a short multi-line
+/// summary, e.g. to compare a problem before and after presolving.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProblemStatistics {
+    /// Number of columns.
+    pub num_cols: usize,
+    /// Number of rows.
+    pub num_rows: usize,
+    /// Number of nonzeros in the constraint matrix.
+    pub num_nonzeros: usize,
+    /// Fraction of matrix entries that are nonzero (`0` for an empty matrix).
+    pub density: f64,
+    /// Integer columns with bounds within `[0, 1]`.
+    pub num_binary: usize,
+    /// Integer columns that are not binary.
+    pub num_integer: usize,
+    /// Continuous columns that appear in an equality `a x + b'y = c` with only
+    /// integer columns `y` and integral `b / a` and `c / a`, so they take an
+    /// integer value in every feasible solution.
+    pub num_implied_integer: usize,
+    /// Continuous columns that are not implied integer.
+    pub num_continuous: usize,
+    /// Columns without finite bounds.
+    pub num_free_cols: usize,
+    /// Columns whose lower and upper bounds are equal.
+    pub num_fixed_cols: usize,
+    /// Columns with only a finite lower bound.
+    pub num_lower_bounded_cols: usize,
+    /// Columns with only a finite upper bound.
+    pub num_upper_bounded_cols: usize,
+    /// Columns with two different finite bounds.
+    pub num_boxed_cols: usize,
+    /// Rows with equal, finite sides.
+    pub num_equalities: usize,
+    /// Rows with exactly one finite side.
+    pub num_inequalities: usize,
+    /// Rows with two different finite sides.
+    pub num_ranged_rows: usize,
+    /// Rows without finite sides.
+    pub num_free_rows: usize,
+    /// Range of the absolute values of the nonzero matrix coefficients.
+    pub coefficient_range: Option<ValueRange>,
+    /// Range of the absolute values of the nonzero objective coefficients.
+    pub objective_range: Option<ValueRange>,
+    /// Range of the absolute values of the finite, nonzero column bounds.
+    pub bound_range: Option<ValueRange>,
+    /// Range of the absolute values of the finite, nonzero row sides.
+    pub side_range: Option<ValueRange>,
+    /// Number of constraints of each [`ConstraintClass`]. Ranged rows are
+    /// split into their two inequalities, as in the MIPLIB classification, so
+    /// the counts add up to `num_rows + num_ranged_rows`.
+    pub constraint_classes: BTreeMap<ConstraintClass, usize>,
+}
+
+/// Smallest and largest absolute value of a set of nonzero numbers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValueRange {
+    /// Smallest absolute value.
+    pub min: f64,
+    /// Largest absolute value.
+    pub max: f64,
+}
+
+impl ValueRange {
+    /// Ratio of the largest to the smallest absolute value.
+    pub fn ratio(&self) -> f64 {
+        self.max / self.min
+    }
+
+    /// Range of the absolute values of the finite, nonzero entries of
+    /// `values`, or `None` if there are none.
+    pub(crate) fn of(values: impl IntoIterator<Item = f64>) -> Option<ValueRange> {
+        values
+            .into_iter()
+            .map(f64::abs)
+            .filter(|v| *v != 0.0 && v.is_finite())
+            .fold(None, |range, v| match range {
+                None => Some(ValueRange { min: v, max: v }),
+                Some(r) => Some(ValueRange {
+                    min: r.min.min(v),
+                    max: r.max.max(v),
+                }),
+            })
+    }
+}
+
+/// Constraint types of the MIPLIB 2017 classification.
+///
+/// Each constraint is brought into the form `a'x <= b` (or `a'x = b`) and
+/// assigned the first matching class in the order listed here. A column
+/// counts as binary if it is integer with bounds within `[0, 1]`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConstraintClass {
+    /// No nonzeros.
+    Empty,
+    /// No finite side.
+    Free,
+    /// A single nonzero.
+    Singleton,
+    /// `a x + b y = c`.
+    Aggregation,
+    /// `a x - a y <= b`, with `x` and `y` of the same type.
+    Precedence,
+    /// `a x + b y <= c`, with `x` binary.
+    VariableBound,
+    /// `sum x = 1`, all binary.
+    SetPartitioning,
+    /// `sum x <= 1`, all binary.
+    SetPacking,
+    /// `sum x >= 1`, all binary.
+    SetCovering,
+    /// `sum x = k` with integer `k >= 2`, all binary.
+    Cardinality,
+    /// `sum x <= b` with integer `b >= 2`, all binary.
+    InvariantKnapsack,
+    /// `a'x = b` with integer coefficients, all binary.
+    EquationKnapsack,
+    /// `a'x <= b` with integer coefficients, all binary.
+    Knapsack,
+    /// `a'x <= b` with integer coefficients, all integer.
+    IntegerKnapsack,
+    /// Only binary and continuous columns.
+    MixedBinary,
+    /// Anything else.
+    General,
+}
+
+impl ReducedProblem {
+    /// Computes size and structure statistics of the reduced problem.
+    pub fn statistics(&self) -> ProblemStatistics {
+        ProblemStatistics::compute(self.model())
+    }
+}
+
+impl OriginalProblem {
+    /// Computes size and structure statistics of the original problem.
+    pub fn statistics(&self) -> ProblemStatistics {
+        ProblemStatistics::compute(self.model())
+    }
+}
+
+impl Problem {
+    /// Computes size and structure statistics of the problem built so far.
+    pub fn statistics(&self) -> ProblemStatistics {
+        let data = self.data();
+        let (row_start, col_indices, values) = data.csr();
+        ProblemStatistics::compute(data.model(&row_start, &col_indices, &values))
+    }
+}
+
+impl ProblemStatistics {
+    pub(crate) fn compute(model: ModelRef<'_>) -> Self {
+        let num_cols = model.num_cols();
+        let num_rows = model.num_rows();
+        let num_nonzeros = model.values.len();
+        let density = if num_cols == 0 || num_rows == 0 {
+            0.0
+        } else {
+            num_nonzeros as f64 / (num_cols as f64 * num_rows as f64)
+        };
+
+        let binary: Vec<bool> = (0..num_cols).map(|col| is_binary(&model, col)).collect();
+        let implied_integer = implied_integer_cols(&model);
+
+        let mut stats = ProblemStatistics {
+            num_cols,
+            num_rows,
+            num_nonzeros,
+            density,
+            num_binary: 0,
+            num_integer: 0,
+            num_implied_integer: 0,
+            num_continuous: 0,
+            num_free_cols: 0,
+            num_fixed_cols: 0,
+            num_lower_bounded_cols: 0,
+            num_upper_bounded_cols: 0,
+            num_boxed_cols: 0,
+            num_equalities: 0,
+            num_inequalities: 0,
+            num_ranged_rows: 0,
+            num_free_rows: 0,
+            coefficient_range: ValueRange::of(model.values.iter().copied()),
+            objective_range: ValueRange::of(model.objective.iter().copied()),
+            bound_range: ValueRange::of(model.col_lower.iter().chain(model.col_upper).copied()),
+            side_range: ValueRange::of(model.row_lhs.iter().chain(model.row_rhs).copied()),
+            constraint_classes: BTreeMap::new(),
+        };
+
+        for col in 0..num_cols {
+            if binary[col] {
+                stats.num_binary += 1;
+            } else if model.col_integral[col] {
+                stats.num_integer += 1;
+            } else if implied_integer[col] {
+                stats.num_implied_integer += 1;
+            } else {
+                stats.num_continuous += 1;
+            }
+
+            let lower = model.col_lower[col];
+            let upper = model.col_upper[col];
+            match (lower.is_finite(), upper.is_finite()) {
+                (false, false) => stats.num_free_cols += 1,
+                (true, false) => stats.num_lower_bounded_cols += 1,
+                (false, true) => stats.num_upper_bounded_cols += 1,
+                (true, true) if lower == upper => stats.num_fixed_cols += 1,
+                (true, true) => stats.num_boxed_cols += 1,
+            }
+        }
+
+        for row in 0..num_rows {
+            let lhs = model.row_lhs[row];
+            let rhs = model.row_rhs[row];
+            let (cols, values) = model.row(row);
+            let mut classify = |coefs: &[f64], side: f64, equality: bool| {
+                let class = classify(&model, &binary, cols, coefs, side, equality);
+                *stats.constraint_classes.entry(class).or_insert(0) += 1;
+            };
+
+            match (lhs.is_finite(), rhs.is_finite()) {
+                (false, false) => {
+                    stats.num_free_rows += 1;
+                    let class = if cols.is_empty() {
+                        ConstraintClass::Empty
+                    } else {
+                        ConstraintClass::Free
+                    };
+                    *stats.constraint_classes.entry(class).or_insert(0) += 1;
+                }
+                (true, true) if lhs == rhs => {
+                    stats.num_equalities += 1;
+                    classify(values, rhs, true);
+                }
+                (true, true) => {
+                    stats.num_ranged_rows += 1;
+                    let negated: Vec<f64> = values.iter().map(|v| -v).collect();
+                    classify(values, rhs, false);
+                    classify(&negated, -lhs, false);
+                }
+                (false, true) => {
+                    stats.num_inequalities += 1;
+                    classify(values, rhs, false);
+                }
+                (true, false) => {
+                    stats.num_inequalities += 1;
+                    let negated: Vec<f64> = values.iter().map(|v| -v).collect();
+                    classify(&negated, -lhs, false);
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Number of constraints of the given class.
+    pub fn num_constraints_of(&self, class: ConstraintClass) -> usize {
+        self.constraint_classes.get(&class).copied().unwrap_or(0)
+    }
+}
+
+fn is_integral(value: f64) -> bool {
+    (value - value.round()).abs() <= INTEGRALITY_EPS
+}
+
+fn is_binary(model: &ModelRef<'_>, col: usize) -> bool {
+    model.col_integral[col] && model.col_lower[col] >= 0.0 && model.col_upper[col] <= 1.0
+}
+
+/// Marks the continuous columns that appear in an equality row `a x + b'y = c`
+/// in which all other columns `y` are integer and `b / a` and `c / a` are
+/// integral, so that `x` is integer in every feasible solution.
+fn implied_integer_cols(model: &ModelRef<'_>) -> Vec<bool> {
+    let mut implied = vec![false; model.num_cols()];
+    for row in 0..model.num_rows() {
+        let rhs = model.row_rhs[row];
+        if model.row_lhs[row] != rhs || !rhs.is_finite() {
+            continue;
+        }
+        let (cols, values) = model.row(row);
+        let mut continuous = cols
+            .iter()
+            .zip(values)
+            .filter(|&(&col, _)| !model.col_integral[col]);
+        let (Some((&col, &a)), None) = (continuous.next(), continuous.next()) else {
+            continue;
+        };
+        if is_integral(rhs / a) && values.iter().all(|&b| is_integral(b / a)) {
+            implied[col] = true;
+        }
+    }
+    implied
+}
+
+/// Classifies the constraint `coefs'x <= side` (or `= side` if `equality`)
+/// over the columns `cols`.
+fn classify(
+    model: &ModelRef<'_>,
+    binary: &[bool],
+    cols: &[usize],
+    coefs: &[f64],
+    side: f64,
+    equality: bool,
+) -> ConstraintClass {
+    use ConstraintClass::*;
+
+    match cols.len() {
+        0 => return Empty,
+        1 => return Singleton,
+        2 if equality => return Aggregation,
+        2 => {
+            let (x, y) = (cols[0], cols[1]);
+            let same_type =
+                binary[x] == binary[y] && model.col_integral[x] == model.col_integral[y];
+            if coefs[0] == -coefs[1] && same_type {
+                return Precedence;
+            }
+            if binary[x] || binary[y] {
+                return VariableBound;
+            }
+        }
+        _ => {}
+    }
+
+    let all_binary = cols.iter().all(|&col| binary[col]);
+    let all_integer = cols.iter().all(|&col| model.col_integral[col]);
+    let integral_coefs = coefs.iter().all(|&a| is_integral(a)) && is_integral(side);
+    if all_binary {
+        let all_one = coefs.iter().all(|&a| a == 1.0);
+        let all_minus_one = coefs.iter().all(|&a| a == -1.0);
+        if equality && ((all_one && side == 1.0) || (all_minus_one && side == -1.0)) {
+            return SetPartitioning;
+        }
+        if !equality && all_one && side == 1.0 {
+            return SetPacking;
+        }
+        if !equality && all_minus_one && side == -1.0 {
+            return SetCovering;
+        }
+        if equality
+            && integral_coefs
+            && ((all_one && side >= 2.0) || (all_minus_one && side <= -2.0))
+        {
+            return Cardinality;
+        }
+        if !equality && all_one && integral_coefs && side >= 2.0 {
+            return InvariantKnapsack;
+        }
+        if integral_coefs {
+            return if equality { EquationKnapsack } else { Knapsack };
+        }
+    }
+    if all_integer && integral_coefs && !equality {
+        return IntegerKnapsack;
+    }
+    if cols
+        .iter()
+        .all(|&col| binary[col] || !model.col_integral[col])
+    {
+        return MixedBinary;
+    }
+    General
+}
+
+impl fmt::Display for ValueRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:.1e}, {:.1e}]", self.min, self.max)
+    }
+}
+
+impl fmt::Display for ProblemStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn range(range: &Option<ValueRange>) -> String {
+            range.map_or_else(|| "-".to_owned(), |r| r.to_string())
+        }
+
+        writeln!(
+            f,
+            "{} rows, {} columns, {} nonzeros (density {:.2e})",
+            self.num_rows, self.num_cols, self.num_nonzeros, self.density
+        )?;
+        writeln!(
+            f,
+            "columns: {} binary, {} integer, {} implied integer, {} continuous",
+            self.num_binary, self.num_integer, self.num_implied_integer, self.num_continuous
+        )?;
+        writeln!(
+            f,
+            "bounds:  {} free, {} fixed, {} lower, {} upper, {} boxed",
+            self.num_free_cols,
+            self.num_fixed_cols,
+            self.num_lower_bounded_cols,
+            self.num_upper_bounded_cols,
+            self.num_boxed_cols
+        )?;
+        writeln!(
+            f,
+            "rows:    {} equalities, {} inequalities, {} ranged, {} free",
+            self.num_equalities, self.num_inequalities, self.num_ranged_rows, self.num_free_rows
+        )?;
+        writeln!(
+            f,
+            "ranges:  matrix {}, objective {}, bounds {}, sides {}",
+            range(&self.coefficient_range),
+            range(&self.objective_range),
+            range(&self.bound_range),
+            range(&self.side_range)
+        )?;
+        write!(f, "classes:")?;
+        for (class, count) in &self.constraint_classes {
+            write!(f, " {class:?} {count}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::{Row, original_problem};
+
+    const INF: f64 = f64::INFINITY;
+
+    /// Three binaries `x0..x2`, an integer `x3` in `[0, 10]` and a continuous
+    /// `x4 >= 0`.
+    fn problem(rows: &[Row<'_>]) -> OriginalProblem {
+        let mut problem = original_problem(
+            &[
+                (0.0, 1.0, true),
+                (0.0, 1.0, true),
+                (0.0, 1.0, true),
+                (0.0, 10.0, true),
+                (0.0, INF, false),
+            ],
+            rows,
+        );
+        problem.objective = vec![1.0, 0.0, 0.0, 2.0, -0.5];
+        problem
+    }
+
+    #[test]
+    fn counts_sizes_types_and_bounds() {
+        let stats = problem(&[
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], -INF, 1.0),
+            (&[(3, 2.0), (4, 0.5)], 3.0, 8.0),
+        ])
+        .statistics();
+
+        assert_eq!(
+            (stats.num_rows, stats.num_cols, stats.num_nonzeros),
+            (2, 5, 5)
+        );
+        assert_eq!(stats.density, 0.5);
+        assert_eq!(stats.num_binary, 3);
+        assert_eq!(stats.num_integer, 1);
+        assert_eq!(stats.num_implied_integer, 0);
+        assert_eq!(stats.num_continuous, 1);
+        assert_eq!(stats.num_boxed_cols, 4);
+        assert_eq!(stats.num_lower_bounded_cols, 1);
+        assert_eq!(stats.num_inequalities, 1);
+        assert_eq!(stats.num_ranged_rows, 1);
+        assert_eq!(
+            stats.coefficient_range,
+            Some(ValueRange { min: 0.5, max: 2.0 })
+        );
+        assert_eq!(
+            stats.objective_range,
+            Some(ValueRange { min: 0.5, max: 2.0 })
+        );
+        assert_eq!(
+            stats.bound_range,
+            Some(ValueRange {
+                min: 1.0,
+                max: 10.0
+            })
+        );
+        assert_eq!(stats.side_range, Some(ValueRange { min: 1.0, max: 8.0 }));
+        assert_eq!(stats.coefficient_range.unwrap().ratio(), 4.0);
+    }
+
+    #[test]
+    fn classifies_constraints_in_miplib_order() {
+        let stats = problem(&[
+            (&[], -INF, 0.0),
+            (&[(0, 1.0), (1, 1.0)], -INF, INF),
+            (&[(3, 1.0)], -INF, 4.0),
+            (&[(3, 1.0), (4, -2.0)], 0.0, 0.0),
+            (&[(0, 1.0), (1, -1.0)], -INF, 0.0),
+            (&[(0, -5.0), (4, 1.0)], -INF, 0.0),
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], 1.0, 1.0),
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], -INF, 1.0),
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], 1.0, INF),
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], 2.0, 2.0),
+            (&[(0, 1.0), (1, 1.0), (2, 1.0)], -INF, 2.0),
+            (&[(0, 3.0), (1, 2.0), (2, 1.0)], 4.0, 4.0),
+            (&[(0, 3.0), (1, 2.0), (2, 1.0)], -INF, 4.0),
+            (&[(0, 3.0), (1, 2.0), (3, 1.0)], -INF, 7.0),
+            (&[(0, 3.0), (1, 2.0), (4, 1.5)], -INF, 7.0),
+            (&[(0, 3.0), (3, 2.0), (4, 1.5)], -INF, 7.0),
+        ])
+        .statistics();
+
+        use ConstraintClass::*;
+        for class in [
+            Empty,
+            Free,
+            Singleton,
+            Aggregation,
+            Precedence,
+            VariableBound,
+            SetPartitioning,
+            SetPacking,
+            SetCovering,
+            Cardinality,
+            InvariantKnapsack,
+            EquationKnapsack,
+            Knapsack,
+            IntegerKnapsack,
+            MixedBinary,
+            General,
+        ] {
+            assert_eq!(stats.num_constraints_of(class), 1, "{class:?}");
+        }
+    }
+
+    #[test]
+    fn cardinality_requires_a_reachable_side() {
+        let negated = problem(&[
+            (&[(0, -1.0), (1, -1.0), (2, -1.0)], -2.0, -2.0),
+            (&[(0, -1.0), (1, -1.0), (2, -1.0)], 2.0, 2.0),
+        ])
+        .statistics();
+
+        assert_eq!(negated.num_constraints_of(ConstraintClass::Cardinality), 1);
+        assert_eq!(
+            negated.num_constraints_of(ConstraintClass::EquationKnapsack),
+            1
+        );
+    }
+
+    #[test]
+    fn splits_ranged_rows_into_both_halves() {
+        let stats = problem(&[(&[(0, 1.0), (1, 1.0), (2, 1.0)], 1.0, 2.0)]).statistics();
+
+        assert_eq!(
+            stats.num_constraints_of(ConstraintClass::InvariantKnapsack),
+            1
+        );
+        assert_eq!(stats.num_constraints_of(ConstraintClass::SetCovering), 1);
+    }
+
+    #[test]
+    fn detects_implied_integer_columns() {
+        let stats = problem(&[(&[(3, 2.0), (4, -2.0), (0, 4.0)], 6.0, 6.0)]).statistics();
+
+        assert_eq!(stats.num_implied_integer, 1);
+        assert_eq!(stats.num_continuous, 0);
+
+        let stats = problem(&[(&[(3, 1.0), (4, -2.0)], 6.0, 6.0)]).statistics();
+        assert_eq!(stats.num_implied_integer, 0);
+    }
+
+    #[test]
+    fn display_summarises_statistics() {
+        let text = problem(&[(&[(0, 1.0), (1, 1.0), (2, 1.0)], -INF, 1.0)])
+            .statistics()
+            .to_string();
+
+        assert!(text.starts_with("1 rows, 5 columns, 3 nonzeros"));
+        assert!(text.contains("3 binary, 1 integer, 0 implied integer, 1 continuous"));
+        assert!(text.ends_with("classes: SetPacking 1"));
+    }
+}